@@ -1,11 +1,11 @@
 use std::collections::HashMap;
 
-const INPUT: &str = include_str!("../assets/day01.txt");
+use aoc_runner::Solution;
 
-fn input() -> (Vec<i32>, Vec<i32>) {
+fn parse(input: &str) -> (Vec<i32>, Vec<i32>) {
     let mut left = Vec::new();
     let mut right = Vec::new();
-    for line in INPUT.lines() {
+    for line in input.lines() {
         let numbers: Vec<_> = line
             .split_ascii_whitespace()
             .flat_map(|s| s.parse::<i32>().ok())
@@ -17,7 +17,9 @@ fn input() -> (Vec<i32>, Vec<i32>) {
     (left, right)
 }
 
-fn part1_algo(mut left: Vec<i32>, mut right: Vec<i32>) -> i32 {
+fn part1_algo(left: &[i32], right: &[i32]) -> i32 {
+    let mut left = left.to_vec();
+    let mut right = right.to_vec();
     left.sort();
     right.sort();
     left.iter()
@@ -26,12 +28,7 @@ fn part1_algo(mut left: Vec<i32>, mut right: Vec<i32>) -> i32 {
         .sum()
 }
 
-pub fn part1() -> i32 {
-    let (left, right) = input();
-    part1_algo(left, right)
-}
-
-fn part2_algo(left: Vec<i32>, right: Vec<i32>) -> i32 {
+fn part2_algo(left: &[i32], right: &[i32]) -> i32 {
     let mut counts = HashMap::new();
     for r in right {
         *counts.entry(r).or_insert(0) += 1;
@@ -41,9 +38,23 @@ fn part2_algo(left: Vec<i32>, right: Vec<i32>) -> i32 {
         .sum()
 }
 
-pub fn part2() -> i32 {
-    let (left, right) = input();
-    part2_algo(left, right)
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<i32>, Vec<i32>);
+    type Answer = i32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1((left, right): &Self::Input) -> i32 {
+        part1_algo(left, right)
+    }
+
+    fn part2((left, right): &Self::Input) -> i32 {
+        part2_algo(left, right)
+    }
 }
 
 #[cfg(test)]
@@ -52,17 +63,11 @@ mod tests {
 
     #[test]
     fn part1() {
-        assert_eq!(
-            part1_algo(vec![3, 4, 2, 1, 3, 3], vec![4, 3, 5, 3, 9, 3]),
-            11
-        );
+        assert_eq!(part1_algo(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]), 11);
     }
 
     #[test]
     fn part2() {
-        assert_eq!(
-            part2_algo(vec![3, 4, 2, 1, 3, 3], vec![4, 3, 5, 3, 9, 3]),
-            31
-        );
+        assert_eq!(part2_algo(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]), 31);
     }
 }
@@ -1,10 +1,9 @@
+use aoc_runner::Solution;
 use itertools::Itertools;
 
-const INPUT: &str = include_str!("../assets/day02.txt");
-
-fn input() -> Vec<Vec<i32>> {
+fn parse(input: &str) -> Vec<Vec<i32>> {
     let mut result = Vec::new();
-    for line in INPUT.lines() {
+    for line in input.lines() {
         let numbers: Vec<_> = line
             .split_ascii_whitespace()
             .flat_map(|s| s.parse::<i32>().ok())
@@ -53,18 +52,30 @@ fn is_safe_dampened(report: &[i32]) -> bool {
     false
 }
 
-pub fn part1() -> usize {
-    input().iter().filter(|report| is_safe(report)).count()
-}
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i32>>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
 
-pub fn part2() -> usize {
-    input()
-        .iter()
-        .filter(|report| is_safe_dampened(report))
-        .count()
+    fn part1(input: &Self::Input) -> usize {
+        input.iter().filter(|report| is_safe(report)).count()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input
+            .iter()
+            .filter(|report| is_safe_dampened(report))
+            .count()
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::useless_vec)]
 mod tests {
     use crate::day02::is_safe_dampened;
 
@@ -1,49 +1,80 @@
+use aoc_runner::Solution;
 use regex::Regex;
 
-const INPUT: &str = include_str!("../assets/day03.txt");
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instr {
+    Do,
+    Dont,
+    Mul(i32, i32),
+}
+
+pub fn parse(input: &str) -> Vec<Instr> {
+    let re = Regex::new(r"don't|do|mul\((\d+),(\d+)\)").unwrap();
+    re.captures_iter(input)
+        .map(|cap| match &cap[0] {
+            "do" => Instr::Do,
+            "don't" => Instr::Dont,
+            _ => Instr::Mul(
+                cap[1].parse::<i32>().unwrap(),
+                cap[2].parse::<i32>().unwrap(),
+            ),
+        })
+        .collect()
+}
 
-pub fn part1_algo(input: &str) -> i32 {
-    let re = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+pub fn part1_algo(instrs: &[Instr]) -> i32 {
     let mut result = 0;
-    for cap in re.captures_iter(input) {
-        result += &cap[1].parse::<i32>().unwrap() * &cap[2].parse::<i32>().unwrap();
+    for instr in instrs {
+        if let Instr::Mul(a, b) = instr {
+            result += a * b;
+        }
     }
     result
 }
 
-pub fn part2_algo(input: &str) -> i32 {
-    let re = Regex::new(r"don't|do|mul\((\d+),(\d+)\)").unwrap();
+pub fn part2_algo(instrs: &[Instr]) -> i32 {
     let mut result = 0;
     let mut enabled = true;
-    for cap in re.captures_iter(input) {
-        match &cap[0] {
-            "do" => enabled = true,
-            "don't" => enabled = false,
-            _ if enabled => {
-                result += &cap[1].parse::<i32>().unwrap() * &cap[2].parse::<i32>().unwrap()
-            }
+    for instr in instrs {
+        match instr {
+            Instr::Do => enabled = true,
+            Instr::Dont => enabled = false,
+            Instr::Mul(a, b) if enabled => result += a * b,
             _ => (),
         }
     }
     result
 }
 
-pub fn part1() -> i32 {
-    part1_algo(INPUT)
-}
+pub struct Day03;
 
-pub fn part2() -> i32 {
-    part2_algo(INPUT)
+impl Solution for Day03 {
+    type Input = Vec<Instr>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        part1_algo(input)
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        part2_algo(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day03::{part1_algo, part2_algo};
+    use crate::day03::{parse, part1_algo, part2_algo};
 
     #[test]
     fn test_part1() {
         assert_eq!(
-            part1_algo("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"),
+            part1_algo(&parse(
+                "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"
+            )),
             161
         );
     }
@@ -51,7 +82,9 @@ mod tests {
     #[test]
     fn test_part2() {
         assert_eq!(
-            part2_algo("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"),
+            part2_algo(&parse(
+                "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"
+            )),
             48
         );
     }
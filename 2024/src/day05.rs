@@ -1,63 +1,13 @@
 use std::collections::HashSet;
 
-const INPUT: &str = include_str!("../assets/day05.txt");
+use aoc_common::topo::Topo;
+use aoc_runner::Solution;
 
-struct PageOrdering {
+#[derive(Default)]
+pub struct PageOrdering {
     constraints: HashSet<(usize, usize)>,
 }
 
-struct TopologicalSort {
-    incoming: Vec<HashSet<usize>>,
-    outgoing: Vec<HashSet<usize>>,
-}
-
-impl TopologicalSort {
-    pub fn new(num_nodes: usize) -> Self {
-        Self {
-            incoming: vec![HashSet::new(); num_nodes],
-            outgoing: vec![HashSet::new(); num_nodes],
-        }
-    }
-
-    pub fn add_edge(&mut self, from: usize, to: usize) {
-        self.incoming[to].insert(from);
-        self.outgoing[from].insert(to);
-    }
-
-    pub fn sort(mut self) -> Option<(Vec<usize>, bool)> {
-        let mut result = Vec::with_capacity(self.incoming.len());
-        let mut unique = true;
-        let mut no_incoming = self
-            .incoming
-            .iter()
-            .enumerate()
-            .filter_map(|(i, e)| e.is_empty().then_some(i))
-            .collect::<HashSet<_>>();
-
-        while !no_incoming.is_empty() {
-            if no_incoming.len() > 1 {
-                unique = false;
-            }
-
-            let i = *no_incoming.iter().next().unwrap();
-            result.push(i);
-            no_incoming.remove(&i);
-            for &j in self.outgoing[i].iter() {
-                self.incoming[j].remove(&i);
-                if self.incoming[j].is_empty() {
-                    no_incoming.insert(j);
-                }
-            }
-        }
-
-        if result.len() == self.incoming.len() {
-            Some((result, unique))
-        } else {
-            None
-        }
-    }
-}
-
 impl PageOrdering {
     #[allow(dead_code)]
     fn from_slice(constraints: &[(usize, usize)]) -> Self {
@@ -88,23 +38,27 @@ impl PageOrdering {
     }
 
     pub fn reorder_list(&self, list: &[usize]) -> Option<Vec<usize>> {
-        let mut graph = TopologicalSort::new(list.len());
-        for i in 0..list.len() {
-            for j in i + 1..list.len() {
-                if self.constraints.contains(&(list[i], list[j])) {
-                    graph.add_edge(i, j);
+        let mut graph = Topo::new();
+        for &page in list {
+            graph.add_node(page);
+        }
+        for (i, &a) in list.iter().enumerate() {
+            for &b in &list[i + 1..] {
+                if self.constraints.contains(&(a, b)) {
+                    graph.add_edge(a, b);
                 }
-                if self.constraints.contains(&(list[j], list[i])) {
-                    graph.add_edge(j, i);
+                if self.constraints.contains(&(b, a)) {
+                    graph.add_edge(b, a);
                 }
             }
         }
 
-        if let Some((ordered, true)) = graph.sort() {
-            Some(ordered.iter().map(|&i| list[i]).collect())
-        } else {
-            None
-        }
+        // The ordering is unique when every batch has a single page
+        let batches = graph.sort()?;
+        batches
+            .iter()
+            .all(|b| b.len() == 1)
+            .then(|| batches.into_iter().flatten().collect())
     }
 }
 
@@ -113,7 +67,7 @@ fn parse(input: &str) -> (PageOrdering, Vec<Vec<usize>>) {
     let mut lists = Vec::new();
 
     let mut lines = input.lines();
-    while let Some(line) = lines.next() {
+    for line in lines.by_ref() {
         if line.trim().is_empty() {
             break;
         }
@@ -128,7 +82,7 @@ fn parse(input: &str) -> (PageOrdering, Vec<Vec<usize>>) {
         ordering.add_constraint(a, b);
     }
 
-    while let Some(line) = lines.next() {
+    for line in lines {
         let list: Vec<usize> = line
             .split(",")
             .map(|p| p.parse::<usize>().unwrap())
@@ -140,29 +94,38 @@ fn parse(input: &str) -> (PageOrdering, Vec<Vec<usize>>) {
     (ordering, lists)
 }
 
-pub fn part1() -> usize {
-    let (ordering, lists) = parse(INPUT);
-    let mut result = 0;
-    for list in lists {
-        if ordering.satisfies_list(&list) {
-            result += list[list.len() / 2];
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (PageOrdering, Vec<Vec<usize>>);
+    type Answer = usize;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1((ordering, lists): &Self::Input) -> usize {
+        let mut result = 0;
+        for list in lists {
+            if ordering.satisfies_list(list) {
+                result += list[list.len() / 2];
+            }
         }
+        result
     }
-    result
-}
 
-pub fn part2() -> usize {
-    let (ordering, lists) = parse(INPUT);
-    let mut result = 0;
-    for list in lists {
-        if !ordering.satisfies_list(&list) {
-            let order = ordering
-                .reorder_list(&list)
-                .expect("the list doesn't have a unique re-ordering");
-            result += order[order.len() / 2];
+    fn part2((ordering, lists): &Self::Input) -> usize {
+        let mut result = 0;
+        for list in lists {
+            if !ordering.satisfies_list(list) {
+                let order = ordering
+                    .reorder_list(list)
+                    .expect("the list doesn't have a unique re-ordering");
+                result += order[order.len() / 2];
+            }
         }
+        result
     }
-    result
 }
 
 #[cfg(test)]
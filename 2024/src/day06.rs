@@ -1,106 +1,40 @@
-use std::{
-    collections::HashSet,
-    hash::Hash,
-    ops::{Index, IndexMut},
-};
+use std::collections::HashSet;
 
-const INPUT: &str = include_str!("../assets/day06.txt");
+use aoc_common::grid::{Dir4, Grid};
+use aoc_runner::Solution;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Cell {
+pub enum Cell {
     Obstacle,
     Patrolled,
     Empty,
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
-enum Dir {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Dir {
-    pub fn turn_right(&mut self) {
-        *self = match self {
-            Dir::Up => Dir::Right,
-            Dir::Down => Dir::Left,
-            Dir::Left => Dir::Up,
-            Dir::Right => Dir::Down,
-        }
-    }
-}
-
 type Pos = (usize, usize);
 
-#[derive(Clone)]
-struct Grid {
-    pub cells: Vec<Cell>,
-    cols: usize,
-    rows: usize,
-}
-
-impl Grid {
-    pub fn from_str(str: &str) -> (Self, Pos) {
-        let mut data = Vec::new();
-        let mut cols = None;
-        let mut guard_pos = None;
-        for (y, line) in str.lines().enumerate() {
-            assert_eq!(*cols.get_or_insert(line.len()), line.len());
-            data.extend(line.chars().enumerate().map(|(x, c)| match c {
-                '.' => Cell::Empty,
-                '#' => Cell::Obstacle,
-                '^' => {
-                    assert!(guard_pos.is_none(), "there should be at most guard");
-                    let _ = guard_pos.insert((x, y));
-                    Cell::Empty
-                }
-                _ => panic!("unexpected character in grid"),
-            }));
-        }
-
-        let cols = cols.unwrap();
-        let rows = data.len() / cols;
-        (
-            Self {
-                cells: data,
-                cols,
-                rows,
-            },
-            guard_pos.unwrap(),
-        )
-    }
-
-    pub fn move_guard(&self, &(x, y): &Pos, dir: &Dir) -> Option<Pos> {
-        match dir {
-            Dir::Up => (y > 0).then(|| (x, y - 1)),
-            Dir::Down => (y < self.rows - 1).then(|| (x, y + 1)),
-            Dir::Left => (x > 0).then(|| (x - 1, y)),
-            Dir::Right => (x < self.cols - 1).then(|| (x + 1, y)),
-        }
-    }
-}
-
-impl Index<Pos> for Grid {
-    type Output = Cell;
-    fn index(&self, (x, y): Pos) -> &Self::Output {
-        &self.cells[y * self.rows + x]
-    }
-}
-
-impl IndexMut<Pos> for Grid {
-    fn index_mut(&mut self, (x, y): Pos) -> &mut Self::Output {
-        &mut self.cells[y * self.rows + x]
-    }
+fn parse_grid(input: &str) -> (Grid<Cell>, Pos) {
+    assert_eq!(input.matches('^').count(), 1, "there should be one guard");
+    let guard_pos = input
+        .lines()
+        .enumerate()
+        .find_map(|(y, line)| line.find('^').map(|x| (x, y)))
+        .unwrap();
+    // The guard stands on an empty cell
+    let grid = Grid::parse_with(input, |c| match c {
+        '.' | '^' => Some(Cell::Empty),
+        '#' => Some(Cell::Obstacle),
+        _ => None,
+    })
+    .unwrap();
+    (grid, guard_pos)
 }
 
-fn trace_path(grid: &mut Grid, mut pos: Pos, mut dir: Dir) {
+fn trace_path(grid: &mut Grid<Cell>, mut pos: Pos, mut dir: Dir4) {
     loop {
         grid[pos] = Cell::Patrolled;
-        if let Some(next_pos) = grid.move_guard(&pos, &dir) {
+        if let Some(next_pos) = grid.neighbor(pos, dir) {
             if grid[next_pos] == Cell::Obstacle {
-                dir.turn_right();
+                dir = dir.turn_right();
             } else {
                 pos = next_pos;
             }
@@ -110,26 +44,30 @@ fn trace_path(grid: &mut Grid, mut pos: Pos, mut dir: Dir) {
     }
 }
 
-fn part1_algo(input: &str) -> usize {
-    let (mut grid, guard_pos) = Grid::from_str(input);
-    trace_path(&mut grid, guard_pos, Dir::Up);
-    grid.cells.iter().filter(|&&c| c == Cell::Patrolled).count()
-}
-
-pub fn part1() -> usize {
-    part1_algo(&INPUT)
+fn part1_algo((grid, guard_pos): &(Grid<Cell>, Pos)) -> usize {
+    let mut grid = grid.clone();
+    let guard_pos = *guard_pos;
+    trace_path(&mut grid, guard_pos, Dir4::Up);
+    grid.positions()
+        .filter(|&p| grid[p] == Cell::Patrolled)
+        .count()
 }
 
-fn has_cycle(grid: &Grid, mut pos: Pos, mut dir: Dir, visited: &HashSet<(Pos, Dir)>) -> bool {
+fn has_cycle(
+    grid: &Grid<Cell>,
+    mut pos: Pos,
+    mut dir: Dir4,
+    visited: &HashSet<(Pos, Dir4)>,
+) -> bool {
     let mut visited = visited.clone();
     loop {
         if visited.contains(&(pos, dir)) {
             return true;
         }
         visited.insert((pos, dir));
-        if let Some(next_pos) = grid.move_guard(&pos, &dir) {
+        if let Some(next_pos) = grid.neighbor(pos, dir) {
             if grid[next_pos] == Cell::Obstacle {
-                dir.turn_right();
+                dir = dir.turn_right();
             } else {
                 pos = next_pos;
             }
@@ -139,9 +77,10 @@ fn has_cycle(grid: &Grid, mut pos: Pos, mut dir: Dir, visited: &HashSet<(Pos, Di
     }
 }
 
-fn part2_algo(input: &str) -> usize {
-    let (mut grid, mut pos) = Grid::from_str(input);
-    let mut dir = Dir::Up;
+fn part2_algo((grid, pos): &(Grid<Cell>, Pos)) -> usize {
+    let mut grid = grid.clone();
+    let mut pos = *pos;
+    let mut dir = Dir4::Up;
     let mut visited = HashSet::new();
     let mut tried_obstacles = HashSet::new();
     let mut result = 0;
@@ -149,15 +88,13 @@ fn part2_algo(input: &str) -> usize {
         visited.insert((pos, dir));
         grid[pos] = Cell::Patrolled;
 
-        if let Some(next_pos) = grid.move_guard(&pos, &dir) {
+        if let Some(next_pos) = grid.neighbor(pos, dir) {
             if grid[next_pos] == Cell::Obstacle {
-                dir.turn_right();
+                dir = dir.turn_right();
             } else {
                 if grid[next_pos] == Cell::Empty && !tried_obstacles.contains(&next_pos) {
                     grid[next_pos] = Cell::Obstacle;
-                    let mut next_dir = dir;
-                    next_dir.turn_right();
-                    if has_cycle(&grid, pos, next_dir, &visited) {
+                    if has_cycle(&grid, pos, dir.turn_right(), &visited) {
                         result += 1;
                     }
                     grid[next_pos] = Cell::Empty;
@@ -171,13 +108,28 @@ fn part2_algo(input: &str) -> usize {
     }
 }
 
-pub fn part2() -> usize {
-    part2_algo(&INPUT)
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = (Grid<Cell>, Pos);
+    type Answer = usize;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_grid(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1_algo(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2_algo(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day06::{part1_algo, part2_algo};
+    use crate::day06::{parse_grid, part1_algo, part2_algo};
 
     #[test]
     fn test_part1() {
@@ -192,7 +144,7 @@ mod tests {
             ........#.\n\
             #.........\n\
             ......#...";
-        assert_eq!(part1_algo(grid), 41);
+        assert_eq!(part1_algo(&parse_grid(grid)), 41);
     }
 
     #[test]
@@ -208,6 +160,6 @@ mod tests {
             ........#.\n\
             #.........\n\
             ......#...";
-        assert_eq!(part2_algo(grid), 6);
+        assert_eq!(part2_algo(&parse_grid(grid)), 6);
     }
 }
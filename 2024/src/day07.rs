@@ -1,4 +1,4 @@
-const INPUT: &str = include_str!("../assets/day07.txt");
+use aoc_runner::Solution;
 
 fn parse_input(input: &str) -> Vec<(usize, Vec<usize>)> {
     let mut result = Vec::new();
@@ -24,34 +24,32 @@ fn is_possible_part1(numbers: &[usize], target: usize) -> bool {
         _ => {
             let firsts = &numbers[..numbers.len() - 1];
             let last = numbers[numbers.len() - 1];
-            (target % last == 0 && is_possible_part1(firsts, target / last))
+            (target.is_multiple_of(last) && is_possible_part1(firsts, target / last))
                 || (last <= target && is_possible_part1(firsts, target - last))
         }
     }
 }
 
-fn sum_possible(input: &str, possible: impl Fn(&[usize], usize) -> bool) -> usize {
-    let lines = parse_input(input);
+fn sum_possible(
+    lines: &[(usize, Vec<usize>)],
+    possible: impl Fn(&[usize], usize) -> bool,
+) -> usize {
     let mut result = 0;
     for (target, numbers) in lines {
-        if possible(&numbers, target) {
+        if possible(numbers, *target) {
             result += target;
         }
     }
     result
 }
 
-pub fn part1() -> usize {
-    sum_possible(INPUT, is_possible_part1)
-}
-
 fn unconcat(target: usize, last: usize) -> Option<usize> {
     if last > target {
         None
     } else {
         let num_digits = last.checked_ilog10().unwrap_or(0) + 1;
         let divisor = 10_usize.pow(num_digits);
-        if (target - last) % divisor == 0 {
+        if (target - last).is_multiple_of(divisor) {
             Some((target - last) / divisor)
         } else {
             None
@@ -66,7 +64,7 @@ fn is_possible_part2(numbers: &[usize], target: usize) -> bool {
         _ => {
             let firsts = &numbers[..numbers.len() - 1];
             let last = numbers[numbers.len() - 1];
-            (target % last == 0 && is_possible_part2(firsts, target / last))
+            (target.is_multiple_of(last) && is_possible_part2(firsts, target / last))
                 || (last <= target && is_possible_part2(firsts, target - last))
                 || unconcat(target, last)
                     .map(|new_target| is_possible_part2(firsts, new_target))
@@ -75,13 +73,28 @@ fn is_possible_part2(numbers: &[usize], target: usize) -> bool {
     }
 }
 
-pub fn part2() -> usize {
-    sum_possible(INPUT, is_possible_part2)
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<(usize, Vec<usize>)>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        sum_possible(input, is_possible_part1)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        sum_possible(input, is_possible_part2)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day07::{is_possible_part1, is_possible_part2, sum_possible, unconcat};
+    use crate::day07::{is_possible_part1, is_possible_part2, parse_input, sum_possible, unconcat};
 
     const TEST_INPUT: &str = "\
             190: 10 19\n\
@@ -107,11 +120,17 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(sum_possible(TEST_INPUT, is_possible_part1), 3749);
+        assert_eq!(
+            sum_possible(&parse_input(TEST_INPUT), is_possible_part1),
+            3749
+        );
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(sum_possible(TEST_INPUT, is_possible_part2), 11387);
+        assert_eq!(
+            sum_possible(&parse_input(TEST_INPUT), is_possible_part2),
+            11387
+        );
     }
 }
@@ -1,3 +1,5 @@
+use aoc_runner::{Year, day};
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -5,3 +7,17 @@ pub mod day04;
 pub mod day05;
 pub mod day06;
 pub mod day07;
+
+pub const YEAR: Year = Year {
+    year: 2024,
+    root: env!("CARGO_MANIFEST_DIR"),
+    days: &[
+        day!(1, day01::Day01),
+        day!(2, day02::Day02),
+        day!(3, day03::Day03),
+        day!(4, day04::Day04),
+        day!(5, day05::Day05),
+        day!(6, day06::Day06),
+        day!(7, day07::Day07),
+    ],
+};
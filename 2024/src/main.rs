@@ -1,18 +1,3 @@
-use aoc2024::{day01, day02, day03, day04, day05, day06, day07};
-
 fn main() {
-    println!("Day 01 - Part 1: {}", day01::part1());
-    println!("Day 01 - Part 2: {}", day01::part2());
-    println!("Day 02 - Part 1: {}", day02::part1());
-    println!("Day 02 - Part 2: {}", day02::part2());
-    println!("Day 03 - Part 1: {}", day03::part1());
-    println!("Day 03 - Part 2: {}", day03::part2());
-    println!("Day 04 - Part 1: {}", day04::part1());
-    println!("Day 04 - Part 2: {}", day04::part2());
-    println!("Day 05 - Part 1: {}", day05::part1());
-    println!("Day 05 - Part 2: {}", day05::part2());
-    println!("Day 06 - Part 1: {}", day06::part1());
-    println!("Day 06 - Part 2: {}", day06::part2());
-    println!("Day 07 - Part 1: {}", day07::part1());
-    println!("Day 07 - Part 2: {}", day07::part2());
+    aoc_runner::main(&aoc2024::YEAR);
 }
@@ -1,20 +1,31 @@
 
-const INPUT: &str = include_str!("../assets/dayXY.txt");
+use aoc_runner::Solution;
 
 fn part1_algo(input: &str) -> usize {
     todo!()
 }
 
-pub fn part1() -> usize {
-    part1_algo(INPUT)
-}
-
 fn part2_algo(input: &str) -> usize {
     todo!()
 }
 
-pub fn part2() -> usize {
-    part1_algo(INPUT)
+pub struct DayXY;
+
+impl Solution for DayXY {
+    type Input = String;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Self::Input {
+        input.to_string()
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1_algo(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2_algo(input)
+    }
 }
 
 #[cfg(test)]
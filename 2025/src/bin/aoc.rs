@@ -0,0 +1,3 @@
+fn main() {
+    aoc_runner::main(&aoc2025::YEAR);
+}
@@ -0,0 +1,124 @@
+use aoc_runner::{Param, Params, Solution};
+
+/// The position the dial starts at and its number of positions
+#[derive(Debug, Clone, Copy)]
+pub struct Dial {
+    start: i32,
+    size: i32,
+}
+
+pub struct Input {
+    rotations: Vec<i32>,
+    dial: Dial,
+}
+
+fn parse(input: &str, dial: Dial) -> Input {
+    let rotations = input
+        .lines()
+        .map(|l| l.replace("L", "-").replace("R", "+").parse().unwrap())
+        .collect();
+    Input { rotations, dial }
+}
+
+fn part1(input: &Input) -> usize {
+    let Dial { start, size } = input.dial;
+    let mut lock = start;
+    input
+        .rotations
+        .iter()
+        .filter(|&&i| {
+            lock = (lock + i).rem_euclid(size);
+            lock == 0
+        })
+        .count()
+}
+
+fn count_zeros(lock: i32, offset: i32, size: i32) -> usize {
+    let result = (offset / size).unsigned_abs() as usize;
+    let offset = offset % size;
+    if lock == 0 {
+        result
+    } else if (offset > 0 && lock + offset >= size) || (offset < 0 && lock + offset <= 0) {
+        result + 1
+    } else {
+        result
+    }
+}
+
+fn part2(input: &Input) -> usize {
+    let Dial { start, size } = input.dial;
+    let mut lock = start;
+    let mut result: usize = 0;
+    for &i in input.rotations.iter() {
+        result += count_zeros(lock, i, size);
+        lock = (lock + i).rem_euclid(size);
+    }
+    result
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Input;
+    type Answer = usize;
+
+    const PARAMS: &'static [Param] = &[Param::new("start", "50"), Param::new("size", "100")];
+
+    fn parse(input: &str) -> Input {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Input {
+        let dial = Dial {
+            start: params.get("start"),
+            size: params.get("size"),
+        };
+        parse(input, dial)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_runner::Solution;
+
+    use super::{Day01, Dial, count_zeros, parse, part1, part2};
+
+    #[test]
+    fn test_part1() {
+        let input = Day01::parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82");
+        assert_eq!(part1(&input), 3);
+
+        let input = parse("R3\nR2\nL5", Dial { start: 1, size: 6 });
+        assert_eq!(part1(&input), 1);
+    }
+
+    #[test]
+    fn test_count_zeros() {
+        assert_eq!(count_zeros(50, 50, 100), 1);
+        assert_eq!(count_zeros(50, -50, 100), 1);
+        assert_eq!(count_zeros(50, 60, 100), 1);
+        assert_eq!(count_zeros(50, -60, 100), 1);
+        assert_eq!(count_zeros(50, 160, 100), 2);
+        assert_eq!(count_zeros(50, -160, 100), 2);
+        assert_eq!(count_zeros(0, 100, 100), 1);
+        assert_eq!(count_zeros(0, -100, 100), 1);
+        assert_eq!(count_zeros(99, 101, 100), 2);
+        assert_eq!(count_zeros(0, 200, 100), 2);
+        assert_eq!(count_zeros(0, 50, 100), 0);
+        assert_eq!(count_zeros(0, -50, 100), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day01::parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82");
+        assert_eq!(part2(&input), 6);
+    }
+}
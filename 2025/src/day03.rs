@@ -1,4 +1,4 @@
-const INPUT: &str = include_str!("../../assets/day03.txt");
+use aoc_runner::Solution;
 
 type Input = Vec<Vec<u8>>;
 
@@ -43,15 +43,28 @@ fn part2(input: &Input) -> usize {
     result
 }
 
-pub fn main() {
-    let input = parse(INPUT);
-    println!("Day 03 - Part 1: {}", part1(&input));
-    println!("Day 03 - Part 2: {}", part2(&input));
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Input {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse, part1, part2};
+    use super::{parse, part1, part2};
 
     #[test]
     fn test_part1() {
@@ -1,20 +1,18 @@
 use std::collections::BTreeSet;
 
-use aoc2025::grid::Grid;
+use aoc_common::grid::Grid;
+use aoc_runner::Solution;
 use itertools::Itertools;
 
-const INPUT: &str = include_str!("../../assets/day04.txt");
-
 type Input = Grid<bool>;
 
 fn parse(input: &str) -> Input {
-    Grid::from_lines(input.lines().map(|l| {
-        l.chars().map(|c| match c {
-            '@' => true,
-            '.' => false,
-            _ => panic!("invalid grid character"),
-        })
-    }))
+    // Rolls of paper are drawn with `@` rather than `#`
+    Grid::parse_with(input, |c| match c {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
     .unwrap()
 }
 
@@ -57,15 +55,28 @@ fn part2(input: &Input) -> usize {
     result
 }
 
-pub fn main() {
-    let input = parse(INPUT);
-    println!("Day 04 - Part 1: {}", part1(&input));
-    println!("Day 04 - Part 2: {}", part2(&input));
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Input {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse, part1, part2};
+    use super::{parse, part1, part2};
 
     #[test]
     fn test_part1() {
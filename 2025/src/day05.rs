@@ -1,7 +1,6 @@
+use aoc_runner::Solution;
 use itertools::Itertools;
 
-const INPUT: &str = include_str!("../../assets/day05.txt");
-
 type Input = (Vec<(usize, usize)>, Vec<usize>);
 
 fn parse(input: &str) -> Input {
@@ -67,15 +66,28 @@ fn part2((intervals, _): &Input) -> usize {
     result
 }
 
-pub fn main() {
-    let input = parse(INPUT);
-    println!("Day 05 - Part 1: {}", part1(&input));
-    println!("Day 05 - Part 2: {}", part2(&input));
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Input {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse, part1, part2};
+    use super::{parse, part1, part2};
 
     #[test]
     fn test_part1() {
@@ -1,7 +1,6 @@
+use aoc_runner::Solution;
 use itertools::Itertools;
 
-const INPUT: &str = include_str!("../../assets/day06.txt");
-
 #[derive(Clone, Copy, Debug)]
 enum Op {
     Add,
@@ -88,14 +87,29 @@ fn part2(input: &str) -> usize {
     result
 }
 
-pub fn main() {
-    println!("Day 06 - Part 1: {}", part1(INPUT));
-    println!("Day 06 - Part 2: {}", part2(INPUT));
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+    type Answer = usize;
+
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
+
+    fn part1(input: &String) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &String) -> usize {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_borrow)]
 mod tests {
-    use crate::{part1, part2};
+    use super::{part1, part2};
 
     #[test]
     fn test_part1() {
@@ -0,0 +1,151 @@
+use std::collections::{BTreeSet, HashMap};
+
+use aoc_common::{
+    grid::{Dir4, Grid, GridCell},
+    topo::Topo,
+};
+use aoc_runner::Solution;
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum Cell {
+    Empty,
+    Splitter,
+    Start,
+    Beam,
+}
+
+impl GridCell for Cell {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Cell::Empty),
+            '^' => Some(Cell::Splitter),
+            'S' => Some(Cell::Start),
+            '|' => Some(Cell::Beam),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Cell::Empty => '.',
+            Cell::Splitter => '^',
+            Cell::Start => 'S',
+            Cell::Beam => '|',
+        }
+    }
+}
+
+type Input = (Grid<Cell>, usize, usize);
+
+fn parse(input: &str) -> Input {
+    let grid: Grid<Cell> = Grid::parse(input).unwrap();
+    let (x, y) = grid
+        .positions()
+        .find(|&(x, y)| grid[(x, y)] == Cell::Start)
+        .unwrap();
+    (grid, x, y)
+}
+
+fn part1((grid, x, y): &Input) -> usize {
+    let mut grid = grid.clone();
+    let mut splits = 0;
+    let mut to_process = BTreeSet::new();
+    to_process.extend(grid.neighbor((*x, *y), Dir4::Down));
+    while let Some(pos) = to_process.pop_first() {
+        match grid[pos] {
+            Cell::Empty => {
+                to_process.extend(grid.neighbor(pos, Dir4::Down));
+                grid[pos] = Cell::Beam;
+            }
+            Cell::Splitter => {
+                splits += 1;
+                to_process.extend(grid.neighbor(pos, Dir4::Left));
+                to_process.extend(grid.neighbor(pos, Dir4::Right));
+            }
+            _ => {}
+        }
+    }
+    splits
+}
+
+fn part2((grid, x, y): &Input) -> usize {
+    let Some(start) = grid.neighbor((*x, *y), Dir4::Down) else {
+        // the particle exits the grid right away
+        return 1;
+    };
+    // Gather the topology of the particles paths so that we can compute the final answer in topological order
+    let mut grid = grid.clone();
+    let mut topo = Topo::new();
+    topo.add_node(start);
+    let mut to_process = BTreeSet::from([start]);
+    while let Some(pos) = to_process.pop_first() {
+        let dirs: &[Dir4] = match grid[pos] {
+            Cell::Empty => {
+                grid[pos] = Cell::Beam;
+                &[Dir4::Down]
+            }
+            Cell::Splitter => &[Dir4::Left, Dir4::Right],
+            _ => &[],
+        };
+        for next in dirs.iter().filter_map(|&dir| grid.neighbor(pos, dir)) {
+            topo.add_edge(pos, next);
+            to_process.insert(next);
+        }
+    }
+
+    let mut cell_count = HashMap::new();
+    let mut total_count = 0;
+    cell_count.insert(start, 1);
+    for pos in topo.sort_flat().unwrap() {
+        let c = cell_count[&pos];
+        let dirs: &[Dir4] = match grid[pos] {
+            Cell::Beam => &[Dir4::Down],
+            Cell::Splitter => &[Dir4::Left, Dir4::Right],
+            _ => &[],
+        };
+        for &dir in dirs {
+            match grid.neighbor(pos, dir) {
+                Some(next) => *cell_count.entry(next).or_default() += c,
+                // add exiting particles to the total count
+                None => total_count += c,
+            }
+        }
+    }
+    total_count
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Input {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+
+    #[test]
+    fn test_part1() {
+        let input = parse(include_str!("../fixtures/day07/example1.txt"));
+        assert_eq!(part1(&input), 21);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse(include_str!("../fixtures/day07/example1.txt"));
+        assert_eq!(part2(&input), 40);
+    }
+}
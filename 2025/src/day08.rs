@@ -1,13 +1,12 @@
 use std::cmp::Reverse;
 
-use aoc2025::union_find::{UnionFind, connected_components};
+use aoc_common::union_find::{UnionFind, connected_components};
+use aoc_runner::{Param, Params, Solution};
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
 
-const INPUT: &str = include_str!("../../assets/day08.txt");
-
 #[derive(Debug)]
-struct Point {
+pub struct Point {
     pub x: i32,
     pub y: i32,
     pub z: i32,
@@ -60,33 +59,45 @@ fn part2(points: &Input) -> usize {
     0
 }
 
-pub fn main() {
-    let input = parse(INPUT);
-    println!("Day 08 - Part 1: {}", part1(&input, 1000));
-    println!("Day 08 - Part 2: {}", part2(&input));
+pub struct Day08;
+
+impl Solution for Day08 {
+    /// The points and the number of pairs to connect in part 1
+    type Input = (Input, usize);
+    type Answer = usize;
+
+    const PARAMS: &'static [Param] = &[Param::new("pairs", "1000")];
+
+    fn parse(input: &str) -> Self::Input {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Self::Input {
+        (parse(input), params.get("pairs"))
+    }
+
+    fn part1((points, pairs): &Self::Input) -> usize {
+        part1(points, *pairs)
+    }
+
+    fn part2((points, _): &Self::Input) -> usize {
+        part2(points)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse, part1, part2};
+    use super::{parse, part1, part2};
 
     #[test]
     fn test_part1() {
-        let input = parse(
-            "162,817,812\n57,618,57\n906,360,560\n592,479,940\n352,342,300\n466,668,158\n542,29,236\n\
-             431,825,988\n739,650,466\n52,470,668\n216,146,977\n819,987,18\n117,168,530\n805,96,715\n\
-             346,949,466\n970,615,88\n941,993,340\n862,61,35\n984,92,344\n425,690,689",
-        );
+        let input = parse(include_str!("../fixtures/day08/example1.txt"));
         assert_eq!(part1(&input, 10), 40);
     }
 
     #[test]
     fn test_part2() {
-        let input = parse(
-            "162,817,812\n57,618,57\n906,360,560\n592,479,940\n352,342,300\n466,668,158\n542,29,236\n\
-             431,825,988\n739,650,466\n52,470,668\n216,146,977\n819,987,18\n117,168,530\n805,96,715\n\
-             346,949,466\n970,615,88\n941,993,340\n862,61,35\n984,92,344\n425,690,689",
-        );
+        let input = parse(include_str!("../fixtures/day08/example1.txt"));
         assert_eq!(part2(&input), 25272);
     }
 }
@@ -1,10 +1,9 @@
 use std::cmp::Reverse;
 
-use aoc2025::grid::Grid;
+use aoc_common::grid::Grid;
+use aoc_runner::Solution;
 use itertools::Itertools;
 
-const INPUT: &str = include_str!("../../assets/day09.txt");
-
 type Input = Vec<(usize, usize)>;
 
 fn parse(input: &str) -> Input {
@@ -133,15 +132,28 @@ fn part2(input: &Input) -> usize {
         .map_or(0, |(_, _, a)| a)
 }
 
-pub fn main() {
-    let input = parse(INPUT);
-    println!("Day 09 - Part 1: {}", part1(&input));
-    println!("Day 09 - Part 2: {}", part2(&input));
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Input {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse, part1, part2};
+    use super::{parse, part1, part2};
 
     #[test]
     fn test_part1() {
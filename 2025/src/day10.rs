@@ -3,12 +3,11 @@ use std::{
     collections::{BinaryHeap, HashSet},
 };
 
+use aoc_runner::Solution;
 use regex::Regex;
 
-const INPUT: &str = include_str!("../../assets/day10.txt");
-
 #[derive(Debug)]
-struct Problem {
+pub struct Problem {
     pub target: usize,
     pub switches: Vec<usize>,
 }
@@ -93,19 +92,32 @@ fn part1(input: &Input) -> usize {
     input.iter().map(|p| num_switches(p).unwrap()).sum()
 }
 
-fn part2(input: &Input) -> usize {
+fn part2(_input: &Input) -> usize {
     unimplemented!()
 }
 
-pub fn main() {
-    let input = parse(INPUT);
-    println!("Day 10 - Part 1: {}", part1(&input));
-    println!("Day 10 - Part 2: {}", part2(&input));
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Input {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse, part1, part2};
+    use super::{parse, part1, part2};
 
     #[test]
     fn test_part1() {
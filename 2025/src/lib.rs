@@ -1,3 +1,29 @@
-pub mod grid;
-pub mod topo;
-pub mod union_find;
+use aoc_runner::{Year, day};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+
+pub const YEAR: Year = Year {
+    year: 2025,
+    root: env!("CARGO_MANIFEST_DIR"),
+    days: &[
+        day!(1, day01::Day01),
+        day!(2, day02::Day02),
+        day!(3, day03::Day03),
+        day!(4, day04::Day04),
+        day!(5, day05::Day05),
+        day!(6, day06::Day06),
+        day!(7, day07::Day07),
+        day!(8, day08::Day08),
+        day!(9, day09::Day09),
+        day!(10, day10::Day10),
+    ],
+};
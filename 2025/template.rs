@@ -1,4 +1,4 @@
-const INPUT: &str = include_str!("../../assets/dayXY.txt");
+use aoc_runner::Solution;
 
 type Input = ();
 
@@ -14,15 +14,28 @@ fn part2(input: &Input) -> usize {
     unimplemented!()
 }
 
-pub fn main() {
-    let input = parse(INPUT);
-    println!("Day XY - Part 1: {}", part1(&input));
-    println!("Day XY - Part 2: {}", part2(&input));
+pub struct DayXY;
+
+impl Solution for DayXY {
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Input {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse, part1, part2};
+    use super::{parse, part1, part2};
 
     #[test]
     fn test_part1() {
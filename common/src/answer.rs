@@ -0,0 +1,103 @@
+use std::fmt;
+
+use crate::{grid::Grid, ocr};
+
+/// The answer of a part: a number, some text, or letters drawn on a grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+    /// Displayed as the letters it shows when they are recognized, and drawn
+    /// with `#` and `.` otherwise
+    Bitmap(Grid<bool>),
+}
+
+impl Answer {
+    /// Name of the variant, `int`, `str` or `bitmap`, reported as the type of the answer
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Int(_) => "int",
+            Answer::Str(_) => "str",
+            Answer::Bitmap(_) => "bitmap",
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                /// Panics for a `u128` above `i128::MAX`, the only value which does not fit
+                fn from(value: $t) -> Self {
+                    Answer::Int(i128::try_from(value).expect("the answer does not fit in an i128"))
+                }
+            }
+        )*
+    };
+}
+
+from_int!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_string())
+    }
+}
+
+impl From<Grid<bool>> for Answer {
+    fn from(value: Grid<bool>) -> Self {
+        Answer::Bitmap(value)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(i) => write!(f, "{i}"),
+            Answer::Str(s) => f.write_str(s),
+            Answer::Bitmap(grid) => match ocr::recognize(grid) {
+                Some(text) => f.write_str(&text),
+                None => write!(f, "\n{grid}"),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+    use crate::grid::Grid;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42usize).to_string(), "42");
+        assert_eq!(Answer::from(-7).to_string(), "-7");
+        assert_eq!(Answer::from(200u8).to_string(), "200");
+        assert_eq!(
+            Answer::from(u128::from(u64::MAX) + 1).to_string(),
+            "18446744073709551616"
+        );
+        assert_eq!(Answer::from("1,2,3").to_string(), "1,2,3");
+
+        let grid = Grid::parse(".##.\n#..#\n#..#\n####\n#..#\n#..#").unwrap();
+        assert_eq!(Answer::from(grid).to_string(), "A");
+
+        let grid = Grid::parse("#.\n.#").unwrap();
+        assert_eq!(Answer::from(grid).to_string(), "\n#.\n.#");
+    }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(Answer::from(42usize).kind(), "int");
+        assert_eq!(Answer::from("abc").kind(), "str");
+        assert_eq!(Answer::from(Grid::parse("#").unwrap()).kind(), "bitmap");
+    }
+}
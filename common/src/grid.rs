@@ -0,0 +1,618 @@
+use std::{
+    fmt, iter,
+    ops::{Index, IndexMut},
+};
+
+use itertools::Itertools;
+
+/// A cell of a grid read from and drawn as a character
+pub trait GridCell: Sized {
+    /// The cell drawn as `c`, or `None` when `c` is not a valid cell
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+impl GridCell for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// `#` for a lit pixel and `.` for an unlit one, as in the bitmaps of the puzzles
+impl GridCell for bool {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+/// A direction of a step on a grid
+pub trait Direction: Copy {
+    /// The `(dx, dy)` of a step in this direction, with `y` growing downwards
+    fn delta(self) -> (isize, isize);
+}
+
+/// One of the 4 orthogonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// All the directions, clockwise from `Up`
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// The direction a quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The direction a quarter turn counterclockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+impl Direction for Dir4 {
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Dir4::Up => (0, -1),
+            Dir4::Right => (1, 0),
+            Dir4::Down => (0, 1),
+            Dir4::Left => (-1, 0),
+        }
+    }
+}
+
+/// One of the 8 directions, orthogonal or diagonal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// All the directions, clockwise from `Up`
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// The direction an eighth of a turn clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The direction an eighth of a turn counterclockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl Direction for Dir8 {
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Dir8::Up => (0, -1),
+            Dir8::UpRight => (1, -1),
+            Dir8::Right => (1, 0),
+            Dir8::DownRight => (1, 1),
+            Dir8::Down => (0, 1),
+            Dir8::DownLeft => (-1, 1),
+            Dir8::Left => (-1, 0),
+            Dir8::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn fill_with_elem(width: usize, height: usize, elem: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::fill_with(width, height, |_| elem.clone())
+    }
+
+    pub fn fill_with(width: usize, height: usize, func: impl Fn((usize, usize)) -> T) -> Self {
+        let mut grid = Self {
+            cells: Vec::with_capacity(width * height),
+            width,
+            height,
+        };
+        for pos in grid.positions() {
+            grid.cells.push(func(pos));
+        }
+        grid
+    }
+
+    pub fn from_lines(lines: impl Iterator<Item = impl Iterator<Item = T>>) -> Option<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in lines {
+            let size_before = cells.len();
+            cells.extend(line);
+            let line_size = cells.len() - size_before;
+            if let Some(w) = width {
+                if w != line_size {
+                    return None;
+                }
+            } else {
+                width = Some(line_size);
+            }
+            height += 1;
+        }
+        Some(Grid {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    /// Reads a grid from the lines of a text, one character per cell
+    pub fn parse(input: &str) -> Result<Self, String>
+    where
+        T: GridCell,
+    {
+        Self::parse_with(input, T::from_char)
+    }
+
+    /// Reads a grid from the lines of a text, with `from_char` telling the cell
+    /// drawn by each character, or `None` for an invalid character
+    pub fn parse_with(input: &str, from_char: impl Fn(char) -> Option<T>) -> Result<Self, String> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let cell = from_char(c).ok_or_else(|| {
+                    format!("row {}, column {}: invalid character {c:?}", y + 1, x + 1)
+                })?;
+                cells.push(cell);
+            }
+            let line_width = line.chars().count();
+            match width {
+                Some(w) if w != line_width => {
+                    return Err(format!(
+                        "row {}: {line_width} cells instead of {w} as in the first row",
+                        y + 1
+                    ));
+                }
+                _ => width = Some(line_width),
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// The position `(dx, dy)` away from `pos`, or `None` when it is outside the grid
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+
+    /// The position next to `pos` in a direction, or `None` on the edge of the grid
+    pub fn neighbor(&self, pos: (usize, usize), dir: impl Direction) -> Option<(usize, usize)> {
+        self.offset(pos, dir.delta())
+    }
+
+    /// The positions next to `pos` in the given directions, in their order,
+    /// skipping the ones outside the grid
+    pub fn neighbors_in<D: Direction>(
+        &self,
+        pos: (usize, usize),
+        dirs: impl IntoIterator<Item = D>,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        dirs.into_iter()
+            .filter_map(move |dir| self.neighbor(pos, dir))
+    }
+
+    /// The up to 4 positions orthogonally next to `pos`, clockwise from above it
+    pub fn orthogonal_neighbors(
+        &self,
+        pos: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors_in(pos, Dir4::ALL)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        (0..self.height)
+            .cartesian_product(0..self.width)
+            .map(|(y, x)| (x, y))
+    }
+
+    /// The grid turned a quarter clockwise
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::fill_with(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// The grid mirrored left to right
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::fill_with(self.width, self.height, |(x, y)| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Finds the occurrences of a pattern, whose `None` cells match anything.
+    /// Returns the position of the upper left corner of each occurrence, and
+    /// none for an empty pattern.
+    ///
+    /// With `symmetries`, the rotations and reflections of the pattern are
+    /// searched too, so the same position may be returned once for each
+    /// distinct transformation of the pattern found there.
+    pub fn find_pattern(&self, pattern: &Grid<Option<T>>, symmetries: bool) -> Vec<(usize, usize)>
+    where
+        T: Clone + PartialEq,
+    {
+        let mut variants = vec![pattern.clone()];
+        if symmetries {
+            for i in 0..7 {
+                let next = if i == 3 {
+                    variants[0].flip_horizontal()
+                } else {
+                    variants[i].rotate_right()
+                };
+                variants.push(next);
+            }
+            let mut distinct: Vec<Grid<Option<T>>> = Vec::new();
+            for variant in variants {
+                if !distinct.contains(&variant) {
+                    distinct.push(variant);
+                }
+            }
+            variants = distinct;
+        }
+
+        let mut matches = Vec::new();
+        for variant in &variants {
+            // An empty pattern would match anywhere, even past the edges of the grid
+            if variant.width == 0 || variant.height == 0 {
+                continue;
+            }
+            if variant.width > self.width || variant.height > self.height {
+                continue;
+            }
+            // Only the cells the pattern constrains are compared, found by
+            // their offset from the corner in the cells of the grid
+            let constrained: Vec<(usize, &T)> = variant
+                .positions()
+                .filter_map(|(x, y)| variant[(x, y)].as_ref().map(|c| (y * self.width + x, c)))
+                .collect();
+            for y in 0..=self.height - variant.height {
+                for x in 0..=self.width - variant.width {
+                    let corner = y * self.width + x;
+                    if constrained
+                        .iter()
+                        .all(|&(offset, c)| self.cells[corner + offset] == *c)
+                    {
+                        matches.push((x, y));
+                    }
+                }
+            }
+        }
+        matches
+    }
+
+    /// The positions from `pos` included, stepping in a direction until leaving the grid
+    pub fn ray(
+        &self,
+        pos: (usize, usize),
+        dir: impl Direction,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let start = self.get(pos).is_some().then_some(pos);
+        iter::successors(start, move |&p| self.neighbor(p, dir))
+    }
+
+    /// The positions of each row, from top to bottom, each from left to right
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        (0..self.height).map(|y| self.ray((0, y), Dir4::Right))
+    }
+
+    /// The positions of each column, from left to right, each from top to bottom
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        (0..self.width).map(|x| self.ray((x, 0), Dir4::Down))
+    }
+
+    /// The positions of each diagonal going towards the lower right, from the
+    /// one in the lower left corner to the one in the upper right corner
+    pub fn diags(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        let starts = (0..self.height).rev().map(|y| (0, y));
+        let starts = starts.chain((1..self.width).map(|x| (x, 0)));
+        starts
+            .filter(|&p| self.get(p).is_some())
+            .map(|p| self.ray(p, Dir8::DownRight))
+    }
+
+    /// The positions of each diagonal going towards the upper right, from the
+    /// one in the upper left corner to the one in the lower right corner
+    pub fn anti_diags(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        let starts = (0..self.height).map(|y| (0, y));
+        let last = self.height.saturating_sub(1);
+        let starts = starts.chain((1..self.width).map(move |x| (x, last)));
+        starts
+            .filter(|&p| self.get(p).is_some())
+            .map(|p| self.ray(p, Dir8::UpRight))
+    }
+
+    /// The up to 8 positions next to `pos`, diagonals included, clockwise from above it
+    pub fn adjacent_cells(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors_in(pos, Dir8::ALL)
+    }
+}
+
+/// Draws the rows of the grid on separate lines
+impl<T: GridCell> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            let row: String = (0..self.width).map(|x| self[(x, y)].to_char()).collect();
+            f.write_str(&row)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dir4, Dir8, Direction, Grid, GridCell};
+
+    #[test]
+    fn test_checked_access() {
+        let mut grid = Grid::fill_with(3, 2, |(x, y)| x + 10 * y);
+        assert_eq!(grid.get((2, 1)), Some(&12));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        *grid.get_mut((1, 0)).unwrap() = 7;
+        assert_eq!(grid[(1, 0)], 7);
+        assert!(grid.get_mut((usize::MAX, 0)).is_none());
+
+        assert_eq!(grid.offset((0, 0), (2, 1)), Some((2, 1)));
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((2, 1), (1, 0)), None);
+        assert_eq!(grid.offset((2, 1), (-2, -1)), Some((0, 0)));
+
+        assert_eq!(grid.neighbor((1, 1), Dir4::Up), Some((1, 0)));
+        assert_eq!(grid.neighbor((1, 1), Dir4::Down), None);
+        assert_eq!(grid.neighbor((0, 1), Dir4::Left), None);
+        assert_eq!(grid.neighbor((0, 1), Dir4::Right), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.reverse(), Dir4::Right);
+        assert_eq!(Dir8::UpLeft.turn_right(), Dir8::Up);
+        assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+        assert_eq!(Dir8::DownLeft.reverse(), Dir8::UpRight);
+        for dir in Dir4::ALL {
+            assert_eq!(Dir8::from(dir).delta(), dir.delta());
+            let (dx, dy) = dir.delta();
+            assert_eq!(dir.reverse().delta(), (-dx, -dy));
+            assert_eq!(dir.turn_right().turn_left(), dir);
+        }
+        for dir in Dir8::ALL {
+            let (dx, dy) = dir.delta();
+            assert_eq!(dir.reverse().delta(), (-dx, -dy));
+        }
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::fill_with_elem(3, 3, ());
+        let orthogonal: Vec<_> = grid.orthogonal_neighbors((1, 1)).collect();
+        assert_eq!(orthogonal, [(1, 0), (2, 1), (1, 2), (0, 1)]);
+        let orthogonal: Vec<_> = grid.orthogonal_neighbors((0, 0)).collect();
+        assert_eq!(orthogonal, [(1, 0), (0, 1)]);
+        assert_eq!(grid.adjacent_cells((1, 1)).count(), 8);
+        let adjacent: Vec<_> = grid.adjacent_cells((2, 0)).collect();
+        assert_eq!(adjacent, [(2, 1), (1, 1), (1, 0)]);
+        let diagonal: Vec<_> = grid
+            .neighbors_in((0, 1), [Dir8::UpRight, Dir8::UpLeft, Dir8::DownRight])
+            .collect();
+        assert_eq!(diagonal, [(1, 0), (1, 2)]);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Wall,
+        Floor,
+    }
+
+    impl GridCell for Tile {
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '#' => Some(Tile::Wall),
+                '.' => Some(Tile::Floor),
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            match self {
+                Tile::Wall => '#',
+                Tile::Floor => '.',
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid: Grid<Tile> = Grid::parse("#..\n.#.\n").unwrap();
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid[(1, 1)], Tile::Wall);
+        assert_eq!(grid[(2, 1)], Tile::Floor);
+        assert_eq!(grid.to_string(), "#..\n.#.");
+
+        assert_eq!(
+            Grid::<Tile>::parse("#..\n.#x"),
+            Err("row 2, column 3: invalid character 'x'".to_string())
+        );
+        assert_eq!(
+            Grid::<Tile>::parse("#..\n.#"),
+            Err("row 2: 2 cells instead of 3 as in the first row".to_string())
+        );
+        assert_eq!(Grid::<bool>::parse("").unwrap().width, 0);
+
+        let grid = Grid::parse_with("@.", |c| (c != 'x').then_some(c == '@')).unwrap();
+        assert_eq!(grid.to_string(), "#.");
+        let grid: Grid<char> = Grid::parse("ab\ncd").unwrap();
+        assert_eq!(grid[(0, 1)], 'c');
+    }
+
+    /// The letters of each line of a grid
+    fn letters(
+        grid: &Grid<char>,
+        lines: impl Iterator<Item = impl Iterator<Item = (usize, usize)>>,
+    ) -> Vec<String> {
+        lines.map(|l| l.map(|p| grid[p]).collect()).collect()
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid: Grid<char> = Grid::parse("ABCD\nEFGH\nIJKL").unwrap();
+        assert_eq!(letters(&grid, grid.rows()), ["ABCD", "EFGH", "IJKL"]);
+        assert_eq!(letters(&grid, grid.cols()), ["AEI", "BFJ", "CGK", "DHL"]);
+        assert_eq!(
+            letters(&grid, grid.diags()),
+            ["I", "EJ", "AFK", "BGL", "CH", "D"]
+        );
+        assert_eq!(
+            letters(&grid, grid.anti_diags()),
+            ["A", "EB", "IFC", "JGD", "KH", "L"]
+        );
+        let rays = [
+            grid.ray((2, 2), Dir8::UpLeft),
+            grid.ray((1, 0), Dir8::Down),
+            grid.ray((4, 0), Dir8::Left),
+        ];
+        assert_eq!(letters(&grid, rays.into_iter()), ["KFA", "BFJ", ""]);
+
+        let empty: Grid<char> = Grid::parse("").unwrap();
+        assert_eq!(empty.rows().count(), 0);
+        assert_eq!(empty.diags().count(), 0);
+        assert_eq!(empty.anti_diags().count(), 0);
+    }
+
+    #[test]
+    fn test_symmetries() {
+        let grid: Grid<char> = Grid::parse("abc\ndef").unwrap();
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        let back = (0..4).fold(grid.clone(), |g, _| g.rotate_right());
+        assert_eq!(back, grid);
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let grid: Grid<char> = Grid::parse("ab.a\n.bab\nba..").unwrap();
+        let pattern = Grid::parse_with("ab", |c| Some(Some(c))).unwrap();
+        assert_eq!(grid.find_pattern(&pattern, false), [(0, 0), (2, 1)]);
+
+        let wildcard = |c| Some((c != '?').then_some(c));
+        let pattern = Grid::parse_with("b?b", wildcard).unwrap();
+        assert_eq!(grid.find_pattern(&pattern, false), [(1, 1)]);
+
+        // A `b` next to an `a`, in any orientation, which is found twice at
+        // (1, 1) as the `b` there is both left of an `a` and above one
+        let pattern = Grid::parse_with("a\nb", wildcard).unwrap();
+        assert_eq!(grid.find_pattern(&pattern, false), [(3, 0)]);
+        let mut found = grid.find_pattern(&pattern, true);
+        found.sort();
+        assert_eq!(found, [(0, 0), (0, 2), (1, 1), (1, 1), (2, 1), (3, 0)]);
+
+        let too_big = Grid::fill_with_elem(5, 1, None);
+        assert!(grid.find_pattern(&too_big, true).is_empty());
+        let empty = Grid::fill_with_elem(0, 0, None);
+        assert!(grid.find_pattern(&empty, true).is_empty());
+        let flat = Grid::fill_with_elem(2, 0, None);
+        assert!(grid.find_pattern(&flat, false).is_empty());
+    }
+}
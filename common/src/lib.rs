@@ -0,0 +1,5 @@
+pub mod answer;
+pub mod grid;
+pub mod ocr;
+pub mod topo;
+pub mod union_find;
@@ -0,0 +1,264 @@
+use crate::grid::Grid;
+
+/// A block-letter font: the height and width of its letters, the spacing
+/// between them and their glyphs, where `#` is a lit pixel
+struct Font {
+    height: usize,
+    stride: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+/// The small font, 4 pixels wide (5 for the Y) and 6 high, with 1 pixel between letters
+const SMALL: Font = Font {
+    height: 6,
+    stride: 5,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+/// The large font, 6 pixels wide and 10 high, with 2 pixels between letters
+const LARGE: Font = Font {
+    height: 10,
+    stride: 8,
+    glyphs: &[
+        (
+            'A',
+            &[
+                "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'B',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+                "#....#", "#####.",
+            ],
+        ),
+        (
+            'C',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#....#", ".####.",
+            ],
+        ),
+        (
+            'E',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'F',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'G',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+                "#...##", ".###.#",
+            ],
+        ),
+        (
+            'H',
+            &[
+                "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'J',
+            &[
+                "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+                "#...#.", ".###..",
+            ],
+        ),
+        (
+            'K',
+            &[
+                "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+                "#...#.", "#....#",
+            ],
+        ),
+        (
+            'L',
+            &[
+                "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'N',
+            &[
+                "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+                "#...##", "#....#",
+            ],
+        ),
+        (
+            'P',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'R',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'X',
+            &[
+                "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'Z',
+            &[
+                "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+    ],
+};
+
+impl Font {
+    /// Whether the letter starting at column `x0` of the grid has this glyph
+    fn matches(&self, grid: &Grid<bool>, x0: usize, glyph: &[&str]) -> bool {
+        (0..self.height).all(|y| {
+            let row = glyph[y].as_bytes();
+            (0..self.stride).all(|dx| {
+                let x = x0 + dx;
+                let lit = x < grid.width && grid[(x, y)];
+                lit == (row.get(dx) == Some(&b'#'))
+            })
+        })
+    }
+
+    fn recognize(&self, grid: &Grid<bool>) -> Option<String> {
+        // Screens may have blank columns on both sides of the text, and some
+        // letters start with blank columns, so the first letter may start up to
+        // a stride before the first lit column
+        let lit_column = |x: usize| (0..grid.height).any(|y| grid[(x, y)]);
+        let start = (0..grid.width).find(|&x| lit_column(x))?;
+        let end = (0..grid.width).rfind(|&x| lit_column(x))? + 1;
+        (start.saturating_sub(self.stride - 1)..=start).find_map(|first| {
+            (first..end)
+                .step_by(self.stride)
+                .map(|x0| {
+                    self.glyphs
+                        .iter()
+                        .find(|(_, glyph)| self.matches(grid, x0, glyph))
+                        .map(|&(letter, _)| letter)
+                })
+                .collect()
+        })
+    }
+}
+
+/// Reads the letters drawn in a grid with one of the fonts of the puzzles,
+/// chosen from the height of the grid
+pub fn recognize(grid: &Grid<bool>) -> Option<String> {
+    [SMALL, LARGE]
+        .iter()
+        .find(|font| font.height == grid.height)?
+        .recognize(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::recognize;
+    use crate::grid::Grid;
+
+    fn grid(rows: &[&str]) -> Grid<bool> {
+        Grid::parse(&rows.join("\n")).unwrap()
+    }
+
+    #[test]
+    fn test_small_font() {
+        let screen = grid(&[
+            "..#..#.####.#....####.#...#",
+            "..#..#.#....#....#....#...#",
+            "..####.###..#....###...#.#.",
+            "..#..#.#....#....#......#..",
+            "..#..#.#....#....#......#..",
+            "..#..#.####.####.#......#..",
+        ]);
+        assert_eq!(recognize(&screen), Some("HELFY".to_string()));
+
+        // The I starts with a blank column
+        let screen = grid(&[
+            ".###..##.",
+            "..#..#..#",
+            "..#..#..#",
+            "..#..####",
+            "..#..#..#",
+            ".###.#..#",
+        ]);
+        assert_eq!(recognize(&screen), Some("IA".to_string()));
+    }
+
+    #[test]
+    fn test_large_font() {
+        let screen = grid(&[
+            "#....#..#####...######",
+            "#....#..#....#..#.....",
+            "#....#..#....#..#.....",
+            "#....#..#....#..#.....",
+            "######..#####...#####.",
+            "#....#..#.......#.....",
+            "#....#..#.......#.....",
+            "#....#..#.......#.....",
+            "#....#..#.......#.....",
+            "#....#..#.......#.....",
+        ]);
+        assert_eq!(recognize(&screen), Some("HPF".to_string()));
+
+        // The J starts with three blank columns
+        let screen = grid(&[
+            "...###..#....#",
+            "....#...#....#",
+            "....#...#....#",
+            "....#...#....#",
+            "....#...######",
+            "....#...#....#",
+            "....#...#....#",
+            "#...#...#....#",
+            "#...#...#....#",
+            ".###....#....#",
+        ]);
+        assert_eq!(recognize(&screen), Some("JH".to_string()));
+    }
+
+    #[test]
+    fn test_unknown() {
+        let screen = grid(&["#.#", ".#.", "#.#", ".#.", "#.#", ".#."]);
+        assert_eq!(recognize(&screen), None);
+        assert_eq!(recognize(&grid(&["##", "##"])), None);
+    }
+}
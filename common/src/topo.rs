@@ -47,6 +47,11 @@ where
         self.no_deps.remove(&b);
     }
 
+    /// Adds a node, which is only needed for nodes without any edge
+    pub fn add_node(&mut self, n: N) {
+        self.get_node(n);
+    }
+
     fn get_node(&mut self, n: N) -> usize {
         *self.node_idx.entry(n).or_insert_with(|| {
             let idx = self.nodes.len();
@@ -0,0 +1,241 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use crate::{
+    Day, Params,
+    input::{self, Source},
+    run::catch,
+};
+
+/// The system allocator, counting the allocations of each thread
+pub struct Counting;
+
+thread_local! {
+    static COUNT: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+    static LIVE: Cell<i64> = const { Cell::new(0) };
+    static PEAK: Cell<i64> = const { Cell::new(0) };
+}
+
+fn record(allocated: usize, freed: usize) {
+    if allocated > 0 {
+        COUNT.set(COUNT.get() + 1);
+        BYTES.set(BYTES.get() + allocated as u64);
+    }
+    let live = LIVE.get() + allocated as i64 - freed as i64;
+    LIVE.set(live);
+    PEAK.set(PEAK.get().max(live));
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        record(0, layout.size());
+    }
+
+    /// Counted as a new allocation of the new size, freeing the old one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// The allocations made by the current thread during a phase
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub count: u64,
+    pub bytes: u64,
+    /// Highest amount of memory allocated during the phase and still live at
+    /// some point, on top of what was live before it
+    pub peak: u64,
+}
+
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let (count, bytes, live) = (COUNT.get(), BYTES.get(), LIVE.get());
+    PEAK.set(live);
+    let result = f();
+    let stats = AllocStats {
+        count: COUNT.get() - count,
+        bytes: BYTES.get() - bytes,
+        peak: (PEAK.get() - live).max(0) as u64,
+    };
+    (result, stats)
+}
+
+/// Formats a number of bytes with 3 significant digits and an adapted unit
+pub fn format_bytes(bytes: u64) -> String {
+    let bytes = bytes as f64;
+    let (value, unit) = if bytes < 1024.0 {
+        return format!("{bytes}B");
+    } else if bytes < 1024.0 * 1024.0 {
+        (bytes / 1024.0, "KiB")
+    } else if bytes < 1024.0 * 1024.0 * 1024.0 {
+        (bytes / (1024.0 * 1024.0), "MiB")
+    } else {
+        (bytes / (1024.0 * 1024.0 * 1024.0), "GiB")
+    };
+    let decimals = if value < 10.0 {
+        2
+    } else if value < 100.0 {
+        1
+    } else {
+        0
+    };
+    format!("{value:.decimals$}{unit}")
+}
+
+/// The allocations of each phase of a day, or the message of the panic which
+/// stopped it
+fn count_day(
+    day: &Day,
+    source: &Source,
+    part: Option<u8>,
+    params: &[(String, String)],
+) -> Vec<(String, Result<AllocStats, String>)> {
+    let load = || {
+        let params = Params::resolve(day, source, params)?;
+        catch(|| (input::load(source, day.path, day.embedded), params))
+    };
+    let (raw, params) = match load() {
+        Ok(loaded) => loaded,
+        Err(message) => return vec![("parse".to_string(), Err(message))],
+    };
+    let mut phases = Vec::new();
+    let input = match catch(|| measure(|| day.parse(&raw, &params))) {
+        Ok((input, stats)) => {
+            phases.push(("parse".to_string(), Ok(stats)));
+            input
+        }
+        Err(message) => {
+            phases.push(("parse".to_string(), Err(message)));
+            return phases;
+        }
+    };
+    for p in (1..=2).filter(|&p| part.is_none_or(|part| part == p)) {
+        let stats = catch(|| measure(|| day.solve(&*input, p)).1);
+        phases.push((format!("part {p}"), stats));
+    }
+    phases
+}
+
+/// Counts the allocations of the given days, printing them as they complete
+pub fn run(
+    year: u16,
+    days: &[&Day],
+    source: &Source,
+    part: Option<u8>,
+    params: &[(String, String)],
+) {
+    println!("Year {year} - allocations of the thread running each phase");
+    for day in days {
+        println!(
+            "Day {:02}  {:>10} {:>10} {:>10}",
+            day.day, "count", "allocated", "peak"
+        );
+        for (name, stats) in count_day(day, source, part, params) {
+            match stats {
+                Ok(s) => println!(
+                    "  {name:<6} {:>10} {:>10} {:>10}",
+                    s.count,
+                    format_bytes(s.bytes),
+                    format_bytes(s.peak)
+                ),
+                Err(message) => println!("  {name:<6} panicked: {message}"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_day, format_bytes, measure};
+    use crate::{Day, Solution, input::Source};
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input = Vec<String>;
+        type Answer = usize;
+
+        fn parse(input: &str) -> Vec<String> {
+            input.lines().map(str::to_string).collect()
+        }
+
+        fn part1(input: &Vec<String>) -> usize {
+            input.len()
+        }
+
+        fn part2(input: &Vec<String>) -> usize {
+            input.iter().map(String::len).sum()
+        }
+    }
+
+    static LINES: Day = Day::new::<Lines>(1, "", Some("ab\ncd"));
+
+    #[test]
+    fn test_measure() {
+        let kept = vec![0u8; 100];
+        let (_, stats) = measure(|| {
+            let mut v: Vec<u64> = Vec::with_capacity(1000);
+            v.push(1);
+            drop(v);
+            vec![0u8; 10]
+        });
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.bytes, 8010);
+        assert_eq!(stats.peak, 8000);
+        drop(kept);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(12), "12B");
+        assert_eq!(format_bytes(1536), "1.50KiB");
+        assert_eq!(format_bytes(200 << 20), "200MiB");
+    }
+
+    #[test]
+    fn test_count_day() {
+        let phases = count_day(&LINES, &Source::Default, None, &[]);
+        let names: Vec<_> = phases.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["parse", "part 1", "part 2"]);
+        assert!(phases.iter().all(|(_, stats)| stats.is_ok()));
+
+        // A missing input fails the parse instead of aborting the run
+        let missing = Source::File("/nonexistent/day01.txt".into());
+        let phases = count_day(&LINES, &missing, None, &[]);
+        assert_eq!(phases.len(), 1);
+        let (name, stats) = &phases[0];
+        assert_eq!(name, "parse");
+        assert!(
+            stats
+                .as_ref()
+                .unwrap_err()
+                .starts_with("failed to read the input")
+        );
+    }
+}
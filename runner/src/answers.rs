@@ -0,0 +1,181 @@
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use serde::Deserialize;
+
+/// An answer as written in a TOML file, either a number or a string
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+enum Value {
+    Int(i64),
+    Str(String),
+}
+
+impl Value {
+    fn into_string(self) -> String {
+        match self {
+            Value::Int(i) => i.to_string(),
+            Value::Str(s) => s,
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct Parts {
+    part1: Option<Value>,
+    part2: Option<Value>,
+    #[serde(default)]
+    params: BTreeMap<String, Value>,
+}
+
+/// The expected answers of a single input, e.g. an example, and the values of
+/// the parameters of the puzzle for this input, stored as
+///
+/// ```toml
+/// part1 = 40
+/// part2 = 25272
+///
+/// [params]
+/// pairs = 10
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub params: BTreeMap<String, String>,
+}
+
+impl Expected {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let parts: Parts = toml::from_str(content).map_err(|e| e.to_string())?;
+        Ok(Self {
+            part1: parts.part1.map(Value::into_string),
+            part2: parts.part2.map(Value::into_string),
+            params: parts
+                .params
+                .into_iter()
+                .map(|(name, value)| (name, value.into_string()))
+                .collect(),
+        })
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        if part == 1 {
+            self.part1.as_deref()
+        } else {
+            self.part2.as_deref()
+        }
+    }
+
+    pub fn to_toml(&self) -> String {
+        let line = |key: &str, value: &str| {
+            if value.parse::<i64>().is_ok() {
+                format!("{key} = {value}\n")
+            } else {
+                format!("{key} = {value:?}\n")
+            }
+        };
+        let mut content = String::new();
+        for (part, answer) in [(1, &self.part1), (2, &self.part2)] {
+            if let Some(answer) = answer {
+                content += &line(&format!("part{part}"), answer);
+            }
+        }
+        if !self.params.is_empty() {
+            content += "\n[params]\n";
+            for (name, value) in &self.params {
+                content += &line(name, value);
+            }
+        }
+        content
+    }
+}
+
+/// The confirmed answers of a year, stored in its `answers.toml` as
+///
+/// ```toml
+/// [day01]
+/// part1 = 1066
+/// part2 = "some text"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl Answers {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let days: BTreeMap<String, Parts> = toml::from_str(content).map_err(|e| e.to_string())?;
+        let mut answers = BTreeMap::new();
+        for (key, parts) in days {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<u8>().ok())
+                .ok_or_else(|| format!("invalid day `{key}`, expected e.g. `day07`"))?;
+            for (part, value) in [(1, parts.part1), (2, parts.part2)] {
+                if let Some(value) = value {
+                    answers.insert((day, part), value.into_string());
+                }
+            }
+        }
+        Ok(Self { answers })
+    }
+
+    /// Loads the answers from a file, which may not exist yet
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Expected};
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "[day01]\n\
+             part1 = 1066\n\
+             part2 = \"EHZRUK\"\n\
+             [day10]\n\
+             part1 = 441\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(1, 1), Some("1066"));
+        assert_eq!(answers.get(1, 2), Some("EHZRUK"));
+        assert_eq!(answers.get(10, 1), Some("441"));
+        assert_eq!(answers.get(10, 2), None);
+        assert_eq!(answers.get(2, 1), None);
+
+        assert!(Answers::parse("[first]\npart1 = 1").is_err());
+        assert!(Answers::parse("[day01]\npart1 = [1, 2]").is_err());
+    }
+
+    #[test]
+    fn test_expected() {
+        let mut expected = Expected {
+            part1: Some("21".to_string()),
+            part2: Some("ABC".to_string()),
+            ..Expected::default()
+        };
+        assert_eq!(expected.to_toml(), "part1 = 21\npart2 = \"ABC\"\n");
+        assert_eq!(Expected::parse(&expected.to_toml()), Ok(expected.clone()));
+
+        expected
+            .params
+            .insert("pairs".to_string(), "10".to_string());
+        assert_eq!(
+            expected.to_toml(),
+            "part1 = 21\npart2 = \"ABC\"\n\n[params]\npairs = 10\n"
+        );
+        assert_eq!(Expected::parse(&expected.to_toml()), Ok(expected));
+        assert_eq!(Expected::parse(""), Ok(Expected::default()));
+    }
+}
@@ -0,0 +1,322 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    Day, Params,
+    input::{self, Source},
+    run::{Status, catch, status_of},
+};
+
+/// Timing statistics of a phase over several runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty());
+        samples.sort();
+        let n = samples.len();
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+        Self {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / n as u32,
+        }
+    }
+}
+
+/// Formats a duration with 3 significant digits and an adapted unit
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos() as f64;
+    let (value, unit) = if nanos < 1e3 {
+        (nanos, "ns")
+    } else if nanos < 1e6 {
+        (nanos / 1e3, "µs")
+    } else if nanos < 1e9 {
+        (nanos / 1e6, "ms")
+    } else {
+        (nanos / 1e9, "s")
+    };
+    let decimals = if value < 10.0 {
+        2
+    } else if value < 100.0 {
+        1
+    } else {
+        0
+    };
+    format!("{value:.decimals$}{unit}")
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = black_box(f());
+    (result, start.elapsed())
+}
+
+/// Why a phase could not be timed: its status, `Panicked` or `Unimplemented`, and the panic message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub status: Status,
+    pub message: String,
+}
+
+impl Failure {
+    fn new(message: String) -> Self {
+        Self {
+            status: status_of(&message),
+            message,
+        }
+    }
+
+    /// Short form of the failure for the tables
+    fn label(&self) -> &'static str {
+        match self.status {
+            Status::Unimplemented => "unimplemented",
+            _ => "panicked",
+        }
+    }
+}
+
+/// Timings of the phases of a day. A phase that was not run has no stats, and a
+/// phase that panicked has its failure instead. The parts are not run when the
+/// input could not be loaded or parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Result<Stats, Failure>,
+    pub parts: [Option<Result<Stats, Failure>>; 2],
+}
+
+impl DayBench {
+    /// Sum of the median times of the phases which completed
+    pub fn cost(&self) -> Duration {
+        let parse = self.parse.iter();
+        let parts = self.parts.iter().flatten().flatten();
+        parse.chain(parts).map(|s| s.median).sum()
+    }
+}
+
+/// Times the phases of a day, each under `catch`: a phase which panics is not
+/// run again, and its failure is reported instead of its stats
+pub fn bench_day(
+    day: &Day,
+    source: &Source,
+    part: Option<u8>,
+    params: &[(String, String)],
+    runs: usize,
+    warmup: usize,
+) -> DayBench {
+    let load = || {
+        let params = Params::resolve(day, source, params)?;
+        catch(|| (input::load(source, day.path, day.embedded), params))
+    };
+    let (raw, params) = match load() {
+        Ok(loaded) => loaded,
+        Err(message) => {
+            return DayBench {
+                day: day.day,
+                parse: Err(Failure::new(message)),
+                parts: [None, None],
+            };
+        }
+    };
+    let parts: Vec<u8> = (1..=2)
+        .filter(|&p| part.is_none_or(|part| part == p))
+        .collect();
+    // The samples of the parse and of each part, until they fail
+    let mut samples: [Result<Vec<Duration>, Failure>; 3] =
+        [(); 3].map(|_| Ok(Vec::with_capacity(runs)));
+    for run in 0..warmup + runs {
+        let input = match catch(|| time(|| day.parse(&raw, &params))) {
+            Ok((input, parse_time)) => {
+                if let (Ok(s), true) = (&mut samples[0], run >= warmup) {
+                    s.push(parse_time);
+                }
+                input
+            }
+            Err(message) => {
+                samples[0] = Err(Failure::new(message));
+                break;
+            }
+        };
+        for &p in &parts {
+            let Ok(s) = &mut samples[p as usize] else {
+                continue;
+            };
+            match catch(|| time(|| day.solve(&*input, p)).1) {
+                Ok(t) if run >= warmup => s.push(t),
+                Ok(_) => {}
+                Err(message) => samples[p as usize] = Err(Failure::new(message)),
+            }
+        }
+    }
+    let [parse, part1, part2] = samples;
+    let stats = |samples: Result<Vec<Duration>, Failure>| match samples {
+        Ok(mut s) => (!s.is_empty()).then(|| Ok(Stats::from_samples(&mut s))),
+        Err(failure) => Some(Err(failure)),
+    };
+    DayBench {
+        day: day.day,
+        parse: stats(parse).expect("the parse ran at least once"),
+        parts: [stats(part1), stats(part2)],
+    }
+}
+
+fn print_day(bench: &DayBench) {
+    println!(
+        "Day {:02}  {:>10} {:>10} {:>10}",
+        bench.day, "min", "median", "mean"
+    );
+    let phases = [
+        ("parse", Some(&bench.parse)),
+        ("part 1", bench.parts[0].as_ref()),
+        ("part 2", bench.parts[1].as_ref()),
+    ];
+    for (name, phase) in phases {
+        match phase {
+            Some(Ok(s)) => println!(
+                "  {name:<6} {:>10} {:>10} {:>10}",
+                format_duration(s.min),
+                format_duration(s.median),
+                format_duration(s.mean)
+            ),
+            Some(Err(f)) if f.status == Status::Unimplemented => {
+                println!("  {name:<6} {}", f.label());
+            }
+            Some(Err(f)) => println!("  {name:<6} {}: {}", f.label(), f.message),
+            None => {}
+        }
+    }
+}
+
+fn print_summary(year: u16, benches: &mut [DayBench], runs: usize, warmup: usize) {
+    benches.sort_by_key(|b| std::cmp::Reverse(b.cost()));
+    println!("Year {year} - median of {runs} runs after {warmup} warmup runs");
+    println!(
+        "Day  {:>13} {:>13} {:>13} {:>10}",
+        "parse", "part 1", "part 2", "total"
+    );
+    let cell = |phase: Option<&Result<Stats, Failure>>| match phase {
+        Some(Ok(s)) => format_duration(s.median),
+        Some(Err(f)) => f.label().to_string(),
+        None => "-".to_string(),
+    };
+    for b in benches.iter() {
+        println!(
+            "{:02}   {:>13} {:>13} {:>13} {:>10}",
+            b.day,
+            cell(Some(&b.parse)),
+            cell(b.parts[0].as_ref()),
+            cell(b.parts[1].as_ref()),
+            format_duration(b.cost())
+        );
+    }
+}
+
+/// Benchmarks the given days, printing their timings as they complete and a summary sorted by cost.
+/// A day which panics is reported as such and the next days are still benchmarked.
+pub fn run(
+    year: u16,
+    days: &[&Day],
+    source: &Source,
+    part: Option<u8>,
+    params: &[(String, String)],
+    runs: usize,
+    warmup: usize,
+) {
+    let mut benches = Vec::new();
+    for day in days {
+        let bench = bench_day(day, source, part, params, runs, warmup);
+        print_day(&bench);
+        benches.push(bench);
+    }
+    println!();
+    print_summary(year, &mut benches, runs, warmup);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Stats, bench_day, format_duration};
+    use crate::{Day, Solution, input::Source, run::Status};
+
+    struct Partial;
+
+    impl Solution for Partial {
+        type Input = u64;
+        type Answer = u64;
+
+        fn parse(input: &str) -> u64 {
+            input.trim().parse().unwrap()
+        }
+
+        fn part1(input: &u64) -> u64 {
+            assert!(*input < 10, "too large");
+            *input
+        }
+
+        fn part2(_: &u64) -> u64 {
+            unimplemented!()
+        }
+    }
+
+    static SMALL: Day = Day::new::<Partial>(1, "", Some("5"));
+    static LARGE: Day = Day::new::<Partial>(2, "", Some("10"));
+    static INVALID: Day = Day::new::<Partial>(3, "", Some("x"));
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::from_samples(&mut [ms(4), ms(1), ms(7)]);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(4));
+        assert_eq!(stats.mean, ms(4));
+
+        let stats = Stats::from_samples(&mut [ms(4), ms(1), ms(2), ms(9)]);
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.mean, ms(4));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format_duration(Duration::from_nanos(1_234)), "1.23µs");
+        assert_eq!(format_duration(Duration::from_micros(45_600)), "45.6ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50s");
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let bench = bench_day(&SMALL, &Source::Default, None, &[], 3, 1);
+        assert!(bench.parse.is_ok());
+        assert!(matches!(bench.parts[0], Some(Ok(_))));
+        let failure = bench.parts[1].clone().unwrap().unwrap_err();
+        assert_eq!(failure.status, Status::Unimplemented);
+
+        let bench = bench_day(&LARGE, &Source::Default, Some(1), &[], 3, 1);
+        let failure = bench.parts[0].clone().unwrap().unwrap_err();
+        assert_eq!(failure.status, Status::Panicked);
+        assert!(
+            failure.message.starts_with("too large"),
+            "{}",
+            failure.message
+        );
+        assert!(bench.parts[1].is_none());
+
+        let bench = bench_day(&INVALID, &Source::Default, None, &[], 3, 1);
+        assert_eq!(bench.cost(), Duration::ZERO);
+        assert_eq!(bench.parts, [None, None]);
+        assert_eq!(bench.parse.unwrap_err().status, Status::Panicked);
+    }
+}
@@ -0,0 +1,331 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
+
+use crate::{
+    Day, Params, Year, bench, corpus, examples, fetch,
+    input::{self, InputArgs, Source},
+    output::{self, Format},
+    params, report,
+    run::{self, Options, Status},
+    scaffold,
+    site::SiteArgs,
+    submit, verify, watch,
+};
+
+#[derive(Parser)]
+#[command(subcommand_negates_reqs = true, args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Day to run
+    #[arg(long, required_unless_present_any = ["all", "verify"], conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Only run this part of the day(s)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Run all the days
+    #[arg(long, conflicts_with = "input")]
+    all: bool,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Time parse, part 1 and part 2 separately over several runs instead of printing the answers
+    #[arg(long)]
+    bench: bool,
+
+    /// Number of measured runs of each phase in bench mode
+    #[arg(long, default_value_t = 10, requires = "bench", value_parser = clap::value_parser!(u64).range(1..))]
+    runs: u64,
+
+    /// Number of unmeasured runs before the measured ones in bench mode
+    #[arg(long, default_value_t = 2, requires = "bench")]
+    warmup: u64,
+
+    /// Count the allocations of parse, part 1 and part 2 instead of printing the answers
+    #[cfg(feature = "count-allocs")]
+    #[arg(long, conflicts_with_all = ["bench", "verify"])]
+    allocs: bool,
+
+    /// Run every input of the corpus of the day(s), `DIR/dayXY/*.txt`, checking the
+    /// answers of their `.toml` sidecar [default DIR: the year's `assets`]
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["bench", "verify", "input", "format"])]
+    corpus: Option<Option<PathBuf>>,
+
+    /// Check the answers on the real inputs against the year's `answers.toml`,
+    /// of every day unless `--day` is given
+    #[arg(long, conflicts_with_all = ["bench", "input", "part"])]
+    verify: bool,
+
+    /// Format of the answers
+    #[arg(long, value_enum, default_value_t, conflicts_with_all = ["bench", "verify"])]
+    format: Format,
+
+    /// Give up on a part, or a parse, after this many seconds
+    #[arg(long, value_name = "SECS", value_parser = parse_secs, conflicts_with = "bench")]
+    timeout: Option<Duration>,
+
+    /// Number of days run in parallel with `--all`
+    #[arg(long, default_value_t = 1, requires = "all", conflicts_with_all = ["bench", "verify"], value_parser = clap::value_parser!(u64).range(1..))]
+    jobs: u64,
+
+    /// Set a parameter of the day, e.g. `pairs=10`, over its default and the input's sidecar
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_assignment, requires = "day", conflicts_with = "verify")]
+    params: Vec<(String, String)>,
+
+    /// Re-run the tests of the day and the day itself whenever its source or input changes
+    #[arg(long, requires = "day", conflicts_with_all = ["bench", "verify", "corpus", "format"])]
+    watch: bool,
+
+    /// Report the parts slower than this many seconds in the summary
+    #[arg(long, value_name = "SECS", value_parser = parse_secs, conflicts_with_all = ["bench", "verify"])]
+    warn_after: Option<Duration>,
+}
+
+fn parse_secs(secs: &str) -> Result<Duration, String> {
+    let secs: f64 = secs.parse().map_err(|e| format!("{e}"))?;
+    Duration::try_from_secs_f64(secs).map_err(|e| e.to_string())
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a new day from the year's template, register it and create its empty asset
+    NewDay {
+        /// Year of the day, i.e. the directory containing its crate
+        year: u16,
+        day: u8,
+    },
+    /// Download the input of a day into its asset, unless it is already there
+    Fetch {
+        day: u8,
+
+        #[command(flatten)]
+        site: SiteArgs,
+    },
+    /// Extract the examples of the puzzle page cached in `puzzles/dayXY.html` into `fixtures/dayXY/`
+    Examples { day: u8 },
+    /// Run and time every day, then rewrite the year's section of the README with a table of the progress
+    Report {
+        /// README to update [default: the one at the root of the workspace]
+        #[arg(long, value_name = "PATH")]
+        readme: Option<PathBuf>,
+
+        /// Number of measured runs of each day
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+    },
+    /// Submit an answer, unless the previous guesses of the year already rule it out
+    Submit {
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Answer to submit [default: the answer computed on the day's input]
+        answer: Option<String>,
+
+        #[command(flatten)]
+        site: SiteArgs,
+    },
+}
+
+fn run_command(year: &Year, command: Command) -> Result<(), String> {
+    let root = Path::new(year.root).parent().unwrap();
+    match command {
+        Command::NewDay { year: y, day } => {
+            for path in scaffold::new_day(&root.join(y.to_string()), day)? {
+                println!("Created {}", path.display());
+            }
+        }
+        Command::Fetch { day, site } => {
+            let assets = Path::new(year.root).join("assets");
+            if fetch::fetch_input(&site.client(root)?, year.year, day, &assets)? {
+                println!("Downloaded the input of day {day:02}");
+            } else {
+                println!("The input of day {day:02} is already cached");
+            }
+        }
+        Command::Examples { day } => {
+            for path in examples::extract_day(Path::new(year.root), day)? {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Command::Report { readme, runs } => {
+            let readme = readme.unwrap_or_else(|| root.join("README.md"));
+            report::run(year, &readme, runs as usize)?;
+            println!("Updated {}", readme.display());
+        }
+        Command::Submit {
+            day,
+            part,
+            answer,
+            site,
+        } => {
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    let day = find_day(year, day)?;
+                    let params = Params::resolve(day, &Source::Default, &[])?;
+                    let input = day.parse(
+                        &input::load(&Source::Default, day.path, day.embedded),
+                        &params,
+                    );
+                    day.solve(&*input, part)
+                }
+            };
+            let client = site.client(root)?;
+            let history = Path::new(year.root).join("guesses.toml");
+            let (verdict, wait) =
+                submit::submit(&client, &history, (year.year, day, part), &answer)?;
+            println!("Day {day:02} - Part {part}: {answer}: {verdict}");
+            if let Some(wait) = wait {
+                println!("Try again in {}s", wait.as_secs());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn find_day(year: &Year, day: u8) -> Result<&'static Day, String> {
+    year.days
+        .iter()
+        .find(|d| d.day == day)
+        .ok_or_else(|| format!("Day {day:02} of {} is not solved yet", year.year))
+}
+
+/// Arguments of the runs of a watched day, besides the day itself
+fn watch_args(cli: &Cli) -> Vec<String> {
+    let mut args = Vec::new();
+    if let Some(part) = cli.part {
+        args.extend(["--part".to_string(), part.to_string()]);
+    }
+    if let Some(path) = &cli.input.input {
+        args.extend(["--input".to_string(), path.display().to_string()]);
+    }
+    for (name, value) in &cli.params {
+        args.extend(["--param".to_string(), format!("{name}={value}")]);
+    }
+    if let Some(timeout) = cli.timeout {
+        args.extend(["--timeout".to_string(), timeout.as_secs_f64().to_string()]);
+    }
+    args
+}
+
+/// Entry point of the runner of a year
+pub fn main(year: &Year) {
+    let matches = Cli::command()
+        .about(format!(
+            "Runs the solutions of Advent of Code {}",
+            year.year
+        ))
+        .get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    if let Some(command) = cli.command {
+        if let Err(e) = run_command(year, command) {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+    let source = cli.input.source();
+    // Without `--day`, either `--all` or `--verify` selects every day
+    let days: Vec<&'static Day> = match cli.day {
+        None => year.days.iter().collect(),
+        Some(day) => match find_day(year, day) {
+            Ok(day) => vec![day],
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        },
+    };
+
+    for day in &days {
+        let mut params = Params::defaults(day.params());
+        for (name, value) in &cli.params {
+            if let Err(e) = params.set(name, value) {
+                eprintln!("Day {:02}: {e}", day.day);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if cli.watch {
+        if source == Source::Stdin {
+            eprintln!("Cannot watch the standard input");
+            std::process::exit(1);
+        }
+        watch::watch(Path::new(year.root), days[0], &source, &watch_args(&cli));
+    }
+    #[cfg(feature = "count-allocs")]
+    if cli.allocs {
+        crate::allocs::run(year.year, &days, &source, cli.part, &cli.params);
+        return;
+    }
+    let options = Options {
+        part: cli.part,
+        timeout: cli.timeout,
+        params: cli.params.clone(),
+    };
+    if let Some(dir) = cli.corpus {
+        let dir = dir.unwrap_or_else(|| Path::new(year.root).join("assets"));
+        if !corpus::run(year.year, &days, &dir, &options) {
+            std::process::exit(1);
+        }
+    } else if cli.verify {
+        if !verify::run(year, &days, cli.timeout) {
+            std::process::exit(1);
+        }
+    } else if cli.bench {
+        bench::run(
+            year.year,
+            &days,
+            &source,
+            cli.part,
+            &cli.params,
+            cli.runs as usize,
+            cli.warmup as usize,
+        );
+    } else {
+        let mut records = Vec::new();
+        run::run_days(
+            year.year,
+            &days,
+            &source,
+            &options,
+            cli.jobs as usize,
+            |day_records| {
+                // Text is printed as the days complete, the other formats all at once
+                if cli.format == Format::Text {
+                    print!("{}", output::format(&day_records, Format::Text));
+                }
+                records.extend(day_records);
+            },
+        );
+        if cli.format != Format::Text {
+            print!("{}", output::format(&records, cli.format));
+        } else if cli.all
+            || records
+                .iter()
+                .any(|r| r.status != Status::Ok || cli.warn_after.is_some_and(|warn| r.time > warn))
+        {
+            print!(
+                "\n{}",
+                output::status_table(year.year, &records, cli.warn_after)
+            );
+        }
+        if records
+            .iter()
+            .any(|r| matches!(r.status, Status::Panicked | Status::TimedOut))
+        {
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    Day,
+    answers::Expected,
+    input::Source,
+    run::{self, Options, Status},
+};
+
+/// An input of a corpus, with the answers expected on it from its sidecar file
+pub struct Entry {
+    pub name: String,
+    pub input: PathBuf,
+    pub expected: Result<Expected, String>,
+}
+
+/// Lists the `*.txt` inputs of a corpus directory by name, each with the answers of
+/// the `.toml` file of the same name when there is one
+pub fn entries(dir: &Path) -> Result<Vec<Entry>, String> {
+    let read_dir = fs::read_dir(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+    let mut inputs: Vec<PathBuf> = read_dir
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|e| e == "txt"))
+        .collect();
+    inputs.sort();
+    Ok(inputs
+        .into_iter()
+        .map(|input| {
+            let sidecar = input.with_extension("toml");
+            let expected = match fs::read_to_string(&sidecar) {
+                Ok(content) => {
+                    Expected::parse(&content).map_err(|e| format!("{}: {e}", sidecar.display()))
+                }
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Expected::default()),
+                Err(e) => Err(format!("{}: {e}", sidecar.display())),
+            };
+            Entry {
+                name: input.file_stem().unwrap().to_string_lossy().into_owned(),
+                input,
+                expected,
+            }
+        })
+        .collect())
+}
+
+/// Runs a day on an input of its corpus, printing the outcome of each part.
+/// Returns whether all the expected answers were found.
+fn run_entry(year: u16, day: &'static Day, entry: &Entry, options: &Options) -> bool {
+    let prefix = format!("Day {:02} - {}", day.day, entry.name);
+    let expected = match &entry.expected {
+        Ok(expected) => expected,
+        Err(e) => {
+            println!("{prefix}: invalid expected answers: {e}");
+            return false;
+        }
+    };
+    let source = Source::File(entry.input.clone());
+    let mut pass = true;
+    for record in run::run_day(year, day, &source, options) {
+        let expected = expected.get(record.part);
+        let prefix = format!("{prefix} - Part {}", record.part);
+        match (record.status, &record.answer, expected) {
+            (Status::Ok, Some(answer), Some(expected)) if answer == expected => {
+                println!("{prefix}: ok");
+            }
+            (Status::Ok, Some(answer), Some(expected)) => {
+                pass = false;
+                println!("{prefix}: mismatch");
+                println!("  - {expected}");
+                println!("  + {answer}");
+            }
+            (Status::Ok, Some(answer), None) => println!("{prefix}: {answer} (no expected answer)"),
+            // An unimplemented part only fails when there is an answer to find
+            (Status::Unimplemented, _, None) => println!("{prefix}: unimplemented"),
+            _ => {
+                pass = false;
+                let message = record.message.as_deref().unwrap_or_default();
+                println!("{prefix}: failed: {message}");
+            }
+        }
+    }
+    pass
+}
+
+/// Runs the given days on every input of their corpus, `dir/dayXY/`. Returns
+/// whether all the inputs passed.
+pub fn run(year: u16, days: &[&'static Day], dir: &Path, options: &Options) -> bool {
+    let mut failed = Vec::new();
+    let mut passed = 0;
+    let mut missing = Vec::new();
+    for &day in days {
+        let day_dir = dir.join(format!("day{:02}", day.day));
+        if !day_dir.is_dir() {
+            missing.push(format!("day {:02}", day.day));
+            continue;
+        }
+        let entries = match entries(&day_dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                println!("Day {:02}: {e}", day.day);
+                failed.push(format!("day {:02}", day.day));
+                continue;
+            }
+        };
+        for entry in entries {
+            if run_entry(year, day, &entry, options) {
+                passed += 1;
+            } else {
+                failed.push(format!("day {:02} {}", day.day, entry.name));
+            }
+        }
+    }
+
+    println!();
+    println!("Inputs: {passed} passed, {} failed", failed.len());
+    if !failed.is_empty() {
+        println!("Failed: {}", failed.join(", "));
+    }
+    if !missing.is_empty() {
+        println!("No corpus in {} for {}", dir.display(), missing.join(", "));
+    }
+    failed.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::entries;
+    use crate::test_util::TempDir;
+
+    #[test]
+    fn test_entries() {
+        let dir = TempDir::new("corpus");
+        fs::write(dir.join("bob.txt"), "2").unwrap();
+        fs::write(dir.join("alice.txt"), "1").unwrap();
+        fs::write(dir.join("alice.toml"), "part1 = 3").unwrap();
+        fs::write(dir.join("bob.toml"), "part1 = [").unwrap();
+        fs::write(dir.join("notes.md"), "").unwrap();
+        // An unreadable sidecar is an error, not a missing one
+        fs::write(dir.join("carol.txt"), "3").unwrap();
+        fs::create_dir(dir.join("carol.toml")).unwrap();
+
+        let entries = entries(&dir).unwrap();
+        let names: Vec<_> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["alice", "bob", "carol"]);
+        assert_eq!(entries[0].expected.as_ref().unwrap().get(1), Some("3"));
+        assert_eq!(entries[0].expected.as_ref().unwrap().get(2), None);
+        assert!(entries[1].expected.is_err());
+        assert!(entries[2].expected.is_err());
+    }
+}
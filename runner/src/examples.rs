@@ -0,0 +1,162 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::answers::Expected;
+
+/// The examples and the highlighted example answer of one part of a puzzle page
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PartExamples {
+    pub examples: Vec<String>,
+    /// The last `<code><em>` of the part, which is where the puzzles give the
+    /// answer on the example
+    pub answer: Option<String>,
+}
+
+/// Replaces the HTML entities used by the puzzle pages
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Removes the tags inside a block, e.g. the `<em>` highlighting parts of an example
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some((before, after)) = rest.split_once('<') {
+        text.push_str(before);
+        rest = after.split_once('>').map_or("", |(_, after)| after);
+    }
+    text.push_str(rest);
+    decode(&text)
+}
+
+/// Returns the contents found between every `open` and the following `close`
+fn between<'a>(html: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> {
+    html.split(open)
+        .skip(1)
+        .filter_map(move |chunk| chunk.split_once(close).map(|(inside, _)| inside))
+}
+
+/// Extracts the examples of a cached puzzle page, with one entry per part
+/// visible on the page
+pub fn extract(page: &str) -> Vec<PartExamples> {
+    between(page, "<article class=\"day-desc\">", "</article>")
+        .map(|article| PartExamples {
+            examples: between(article, "<pre><code>", "</code></pre>")
+                .map(strip_tags)
+                .collect(),
+            answer: between(article, "<code><em>", "</em></code>")
+                .last()
+                .map(strip_tags),
+        })
+        .collect()
+}
+
+/// Writes the examples as `exampleK.txt`, each with an `exampleK.toml` holding
+/// the answers expected on it. The answer of a part goes with the first example
+/// of the part, or with the first example of the page when the part has none.
+pub fn write_fixtures(parts: &[PartExamples], dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut examples = Vec::new();
+    let mut expected = Vec::new();
+    for (part, part_examples) in parts.iter().enumerate() {
+        let first = if part_examples.examples.is_empty() {
+            0
+        } else {
+            examples.len()
+        };
+        examples.extend(part_examples.examples.iter());
+        expected.resize_with(examples.len(), Expected::default);
+        match (part, expected.get_mut(first)) {
+            (0, Some(e)) => e.part1.clone_from(&part_examples.answer),
+            (1, Some(e)) => e.part2.clone_from(&part_examples.answer),
+            _ => {}
+        }
+    }
+    if examples.is_empty() {
+        return Err("no example found in the page".to_string());
+    }
+
+    let write = |p: PathBuf, c: &str| {
+        fs::write(&p, c)
+            .map(|_| p.clone())
+            .map_err(|e| format!("{}: {e}", p.display()))
+    };
+    fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+    let mut written = Vec::new();
+    for (k, (example, expected)) in examples.iter().zip(&expected).enumerate() {
+        written.push(write(dir.join(format!("example{}.txt", k + 1)), example)?);
+        if *expected != Expected::default() {
+            written.push(write(
+                dir.join(format!("example{}.toml", k + 1)),
+                &expected.to_toml(),
+            )?);
+        }
+    }
+    Ok(written)
+}
+
+/// Extracts the examples of `puzzles/dayXY.html` into `fixtures/dayXY/`
+pub fn extract_day(year_dir: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let page_path = year_dir.join("puzzles").join(format!("day{day:02}.html"));
+    let page =
+        fs::read_to_string(&page_path).map_err(|e| format!("{}: {e}", page_path.display()))?;
+    let dir = year_dir.join("fixtures").join(format!("day{day:02}"));
+    write_fixtures(&extract(&page), &dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{PartExamples, extract, write_fixtures};
+    use crate::test_util::TempDir;
+
+    const PAGE: &str = "\
+<main>
+<article class=\"day-desc\"><h2>--- Day 1: Test ---</h2>
+<p>For example:</p>
+<pre><code>L68
+<em>R48</em>
+a &lt; b &amp;&amp; c
+</code></pre>
+<p>The dial points at <code>0</code> a total of <code><em>3</em></code> times.</p>
+</article>
+<p>Your puzzle answer was <code>1066</code>.</p>
+<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>
+<p>Following the same rotations, the dial points at zero <code><em>6</em></code> times.</p>
+</article>
+</main>";
+
+    #[test]
+    fn test_extract() {
+        let parts = extract(PAGE);
+        assert_eq!(
+            parts,
+            [
+                PartExamples {
+                    examples: vec!["L68\nR48\na < b && c\n".to_string()],
+                    answer: Some("3".to_string()),
+                },
+                PartExamples {
+                    examples: vec![],
+                    answer: Some("6".to_string()),
+                },
+            ]
+        );
+
+        let dir = TempDir::new("examples");
+        let written = write_fixtures(&parts, &dir).unwrap();
+        assert_eq!(written.len(), 2);
+        assert_eq!(
+            fs::read_to_string(dir.join("example1.toml")).unwrap(),
+            "part1 = 3\npart2 = 6\n"
+        );
+
+        assert!(write_fixtures(&extract("<main></main>"), &dir).is_err());
+    }
+}
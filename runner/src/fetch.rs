@@ -0,0 +1,66 @@
+use std::{fs, path::Path};
+
+use crate::site::Client;
+
+/// Downloads the input of a day into `assets/dayXY.txt`, unless it is already
+/// there and not empty. Returns whether the input was downloaded.
+pub fn fetch_input(client: &Client, year: u16, day: u8, assets: &Path) -> Result<bool, String> {
+    let path = assets.join(format!("day{day:02}.txt"));
+    if fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
+        return Ok(false);
+    }
+    let input = client.get(&format!("/{year}/day/{day}/input"))?;
+    // Write to a temporary file first so that an interrupted write is never taken for a cached input
+    let partial = path.with_extension("txt.part");
+    fs::create_dir_all(assets)
+        .and_then(|_| fs::write(&partial, input))
+        .and_then(|_| fs::rename(&partial, &path))
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::fetch_input;
+    use crate::{
+        site::{Client, mock},
+        test_util::TempDir,
+    };
+
+    #[test]
+    fn test_fetch_input() {
+        let (url, requests) = mock::serve(vec![(200, "1,2\n3,4\n")]);
+        let client = Client::new(&url, "abc".to_string());
+        let assets = TempDir::new("fetch");
+        fs::write(assets.join("day07.txt"), "").unwrap();
+
+        assert_eq!(fetch_input(&client, 2025, 7, &assets), Ok(true));
+        assert_eq!(
+            fs::read_to_string(assets.join("day07.txt")).unwrap(),
+            "1,2\n3,4\n"
+        );
+        let request = requests.recv().unwrap();
+        assert!(request.head.starts_with("GET /2025/day/7/input HTTP/1.1"));
+        assert!(request.head.contains("session=abc"));
+        assert!(
+            request
+                .head
+                .contains("github.com/brunnerant/advent-of-code")
+        );
+
+        // The mock server is gone, so this only succeeds without a request
+        assert_eq!(fetch_input(&client, 2025, 7, &assets), Ok(false));
+    }
+
+    #[test]
+    fn test_fetch_error() {
+        let (url, _requests) = mock::serve(vec![(400, "Puzzle inputs differ by user.\n")]);
+        let client = Client::new(&url, "expired".to_string());
+        let assets = TempDir::new("fetch-error");
+        let error = fetch_input(&client, 2025, 1, &assets).unwrap_err();
+        assert!(error.contains("HTTP 400"), "{error}");
+        assert!(!assets.join("day01.txt").exists());
+    }
+}
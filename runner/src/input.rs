@@ -0,0 +1,76 @@
+use std::{fs, io, io::Read, path::PathBuf};
+
+use clap::Args;
+
+/// Where the puzzle inputs are read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The day's `assets/dayXY.txt`, or the embedded copy with the `embed` feature
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+#[derive(Args, Debug)]
+pub struct InputArgs {
+    /// Read the input from this file instead of the day's asset, or from stdin with `-`
+    #[arg(long, value_name = "PATH", requires = "day")]
+    pub input: Option<PathBuf>,
+}
+
+impl InputArgs {
+    pub fn source(&self) -> Source {
+        match &self.input {
+            None => Source::Default,
+            Some(path) if path.as_os_str() == "-" => Source::Stdin,
+            Some(path) => Source::File(path.clone()),
+        }
+    }
+}
+
+fn read(source: &Source, path: &str, embedded: Option<&'static str>) -> io::Result<String> {
+    match source {
+        Source::Default => match embedded {
+            Some(input) => Ok(input.to_string()),
+            None => fs::read_to_string(path),
+        },
+        Source::File(path) => fs::read_to_string(path),
+        Source::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+    }
+}
+
+/// Loads a puzzle input from the given source. `path` is the default
+/// location of the input, and `embedded` its compile-time copy, if any.
+pub fn load(source: &Source, path: &str, embedded: Option<&'static str>) -> String {
+    read(source, path, embedded)
+        .unwrap_or_else(|e| panic!("failed to read the input ({source:?}, default {path}): {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{Source, read};
+
+    #[test]
+    fn test_read() {
+        let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+        let expected = std::fs::read_to_string(manifest).unwrap();
+        assert_eq!(read(&Source::Default, manifest, None).unwrap(), expected);
+        assert_eq!(read(&Source::Default, manifest, Some("42")).unwrap(), "42");
+        assert_eq!(
+            read(
+                &Source::File(PathBuf::from(manifest)),
+                "missing",
+                Some("42")
+            )
+            .unwrap(),
+            expected
+        );
+        assert!(read(&Source::Default, "missing", None).is_err());
+    }
+}
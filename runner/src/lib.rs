@@ -0,0 +1,25 @@
+#[cfg(feature = "count-allocs")]
+pub mod allocs;
+pub mod answers;
+pub mod bench;
+mod cli;
+pub mod corpus;
+pub mod examples;
+pub mod fetch;
+pub mod input;
+pub mod output;
+pub mod params;
+pub mod report;
+pub mod run;
+pub mod scaffold;
+pub mod site;
+pub mod solution;
+pub mod submit;
+#[cfg(test)]
+mod test_util;
+mod verify;
+pub mod watch;
+
+pub use cli::main;
+pub use params::{Param, Params};
+pub use solution::{Day, Solution, Year};
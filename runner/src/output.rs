@@ -0,0 +1,221 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+
+use crate::{
+    bench::format_duration,
+    run::{Record, Status},
+};
+
+/// How the results of a run are printed
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// One line per part, e.g. `Day 07 - Part 1: 21`
+    #[default]
+    Text,
+    /// A JSON array of records
+    Json,
+    /// CSV records with a header line
+    Csv,
+}
+
+const CSV_HEADER: &str = "year,day,part,answer,type,time_ns,status,message";
+
+/// Quotes a CSV field when it contains a separator, a quote or a line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn csv_line(r: &Record) -> String {
+    let status = match r.status {
+        Status::Ok => "ok",
+        Status::Panicked => "panicked",
+        Status::Unimplemented => "unimplemented",
+        Status::TimedOut => "timed_out",
+    };
+    [
+        r.year.to_string(),
+        r.day.to_string(),
+        r.part.to_string(),
+        csv_field(r.answer.as_deref().unwrap_or_default()),
+        csv_field(r.answer_type.unwrap_or_default()),
+        r.time.as_nanos().to_string(),
+        status.to_string(),
+        csv_field(r.message.as_deref().unwrap_or_default()),
+    ]
+    .join(",")
+}
+
+fn text_line(r: &Record) -> String {
+    let result = match (r.status, &r.answer, &r.message) {
+        (Status::Ok, Some(answer), _) => answer.clone(),
+        (Status::Unimplemented, ..) => "unimplemented".to_string(),
+        (Status::TimedOut, _, message) => {
+            format!("timed out: {}", message.as_deref().unwrap_or_default())
+        }
+        (_, _, message) => format!("panicked: {}", message.as_deref().unwrap_or_default()),
+    };
+    format!("Day {:02} - Part {}: {result}", r.day, r.part)
+}
+
+fn status_cell(r: &Record) -> &'static str {
+    match r.status {
+        Status::Ok => "solved",
+        Status::Panicked => "panicked",
+        Status::Unimplemented => "unimplemented",
+        Status::TimedOut => "timed out",
+    }
+}
+
+/// Summarizes the status of each part in a table, followed by the messages of
+/// the parts that panicked and the solved parts slower than `warn_after`
+pub fn status_table(year: u16, records: &[Record], warn_after: Option<Duration>) -> String {
+    let mut table = format!(
+        "Status of {year}\n{:<6}{:<15}{}\n",
+        "Day", "Part 1", "Part 2"
+    );
+    for day_records in records.chunk_by(|a, b| a.day == b.day) {
+        let cell = |part| {
+            day_records
+                .iter()
+                .find(|r| r.part == part)
+                .map_or("-", status_cell)
+        };
+        let row = format!(
+            "{:<6}{:<15}{}",
+            format!("{:02}", day_records[0].day),
+            cell(1),
+            cell(2)
+        );
+        table += row.trim_end();
+        table += "\n";
+    }
+    for r in records.iter().filter(|r| r.status == Status::Panicked) {
+        table += &text_line(r);
+        table += "\n";
+    }
+    if let Some(warn_after) = warn_after {
+        for r in records
+            .iter()
+            .filter(|r| r.status == Status::Ok && r.time > warn_after)
+        {
+            table += &format!(
+                "Day {:02} - Part {}: slow: took {}, over {}\n",
+                r.day,
+                r.part,
+                format_duration(r.time),
+                format_duration(warn_after)
+            );
+        }
+    }
+    table
+}
+
+/// Formats the records, ending with a line break
+pub fn format(records: &[Record], format: Format) -> String {
+    let lines: Vec<String> = match format {
+        Format::Text => records.iter().map(text_line).collect(),
+        Format::Json => vec![serde_json::to_string_pretty(records).unwrap()],
+        Format::Csv => std::iter::once(CSV_HEADER.to_string())
+            .chain(records.iter().map(csv_line))
+            .collect(),
+    };
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Format, format, status_table};
+    use crate::run::{Record, Status};
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                year: 2025,
+                day: 7,
+                part: 1,
+                answer: Some("21".to_string()),
+                answer_type: Some("usize"),
+                time: Duration::from_micros(12),
+                status: Status::Ok,
+                message: None,
+            },
+            Record {
+                year: 2025,
+                day: 10,
+                part: 2,
+                answer: None,
+                answer_type: None,
+                time: Duration::ZERO,
+                status: Status::Panicked,
+                message: Some("called `Option::unwrap()` on a `None` value, \"oops\"".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            format(&records(), Format::Text),
+            "Day 07 - Part 1: 21\n\
+             Day 10 - Part 2: panicked: called `Option::unwrap()` on a `None` value, \"oops\"\n"
+        );
+        assert_eq!(
+            format(&records(), Format::Csv),
+            "year,day,part,answer,type,time_ns,status,message\n\
+             2025,7,1,21,usize,12000,ok,\n\
+             2025,10,2,,,0,panicked,\"called `Option::unwrap()` on a `None` value, \"\"oops\"\"\"\n"
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&format(&records(), Format::Json)).unwrap();
+        assert_eq!(
+            json[0],
+            serde_json::json!({
+                "year": 2025,
+                "day": 7,
+                "part": 1,
+                "answer": "21",
+                "type": "usize",
+                "time_ns": 12000,
+                "status": "ok",
+                "message": null,
+            })
+        );
+        assert_eq!(json[1]["status"], "panicked");
+        assert_eq!(json[1]["type"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_status_table() {
+        let mut records = records();
+        records.push(Record {
+            year: 2025,
+            day: 11,
+            part: 1,
+            answer: None,
+            answer_type: None,
+            time: Duration::from_secs(10),
+            status: Status::TimedOut,
+            message: Some("exceeded the budget of 10.0s".to_string()),
+        });
+        assert_eq!(
+            status_table(2025, &records, None),
+            "Status of 2025\n\
+             Day   Part 1         Part 2\n\
+             07    solved         -\n\
+             10    -              panicked\n\
+             11    timed out      -\n\
+             Day 10 - Part 2: panicked: called `Option::unwrap()` on a `None` value, \"oops\"\n"
+        );
+        assert!(
+            status_table(2025, &records, Some(Duration::from_micros(10)))
+                .ends_with("Day 07 - Part 1: slow: took 12.0µs, over 10.0µs\n")
+        );
+    }
+}
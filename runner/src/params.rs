@@ -0,0 +1,135 @@
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path, str::FromStr};
+
+use crate::{Day, answers::Expected, input::Source};
+
+/// A named parameter of a puzzle with its default value, e.g. the number of
+/// `pairs` to connect, which differs between the example and the real input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: &'static str) -> Self {
+        Self { name, default }
+    }
+}
+
+/// The values of the parameters of a day
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn defaults(params: &[Param]) -> Self {
+        Self {
+            values: params
+                .iter()
+                .map(|p| (p.name.to_string(), p.default.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Sets the value of a declared parameter
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        if let Some(v) = self.values.get_mut(name) {
+            *v = value.to_string();
+            return Ok(());
+        }
+        let known: Vec<&str> = self.values.keys().map(String::as_str).collect();
+        Err(if known.is_empty() {
+            format!("unknown parameter `{name}`, this day has none")
+        } else {
+            format!(
+                "unknown parameter `{name}`, expected one of {}",
+                known.join(", ")
+            )
+        })
+    }
+
+    /// Gets the value of a parameter, panicking if it is not declared or invalid
+    pub fn get<T: FromStr>(&self, name: &str) -> T
+    where
+        T::Err: Display,
+    {
+        let value = self
+            .values
+            .get(name)
+            .unwrap_or_else(|| panic!("parameter `{name}` is not declared"));
+        value
+            .parse()
+            .unwrap_or_else(|e| panic!("parameter `{name}`: invalid value `{value}`: {e}"))
+    }
+
+    /// The values of the parameters of a day on an input: the defaults, overridden
+    /// by the `[params]` of the input's sidecar file, then by the given `overrides`
+    pub fn resolve(
+        day: &Day,
+        source: &Source,
+        overrides: &[(String, String)],
+    ) -> Result<Self, String> {
+        let mut params = Self::defaults(day.params());
+        for (name, value) in sidecar(day, source)? {
+            params.set(&name, &value)?;
+        }
+        for (name, value) in overrides {
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+}
+
+/// Reads the `[params]` of the `.toml` file next to an input file, e.g.
+/// `assets/day08.toml` for `assets/day08.txt`
+fn sidecar(day: &Day, source: &Source) -> Result<BTreeMap<String, String>, String> {
+    let input = match source {
+        Source::Default if day.embedded.is_none() => Path::new(day.path),
+        Source::File(path) => path.as_path(),
+        Source::Default | Source::Stdin => return Ok(BTreeMap::new()),
+    };
+    let path = input.with_extension("toml");
+    match fs::read_to_string(&path) {
+        Ok(content) => Expected::parse(&content)
+            .map(|e| e.params)
+            .map_err(|e| format!("{}: {e}", path.display())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(e) => Err(format!("{}: {e}", path.display())),
+    }
+}
+
+/// Parses a `name=value` assignment of a parameter
+pub fn parse_assignment(assignment: &str) -> Result<(String, String), String> {
+    let (name, value) = assignment
+        .split_once('=')
+        .ok_or_else(|| format!("expected `name=value`, got `{assignment}`"))?;
+    Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Param, Params, parse_assignment};
+
+    #[test]
+    fn test_params() {
+        let mut params = Params::defaults(&[Param::new("pairs", "1000"), Param::new("size", "7")]);
+        assert_eq!(params.get::<usize>("pairs"), 1000);
+        params.set("pairs", "10").unwrap();
+        assert_eq!(params.get::<usize>("pairs"), 10);
+        assert_eq!(params.get::<u8>("size"), 7);
+        assert!(
+            params
+                .set("pears", "10")
+                .unwrap_err()
+                .contains("pairs, size")
+        );
+        assert!(Params::default().set("pairs", "10").is_err());
+
+        assert_eq!(
+            parse_assignment("pairs = 10"),
+            Ok(("pairs".to_string(), "10".to_string()))
+        );
+        assert!(parse_assignment("pairs").is_err());
+    }
+}
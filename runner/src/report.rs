@@ -0,0 +1,264 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    Day, Year,
+    answers::Answers,
+    bench::{self, format_duration},
+    input::Source,
+    run::{self, Options, Status},
+};
+
+/// How a part appears in the report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartState {
+    /// Its answer is the one recorded in `answers.toml`
+    Solved,
+    /// It has an answer, but none is recorded
+    Unverified,
+    /// Its answer differs from the recorded one
+    Wrong,
+    /// It panicked, timed out or is not implemented
+    Unsolved,
+}
+
+impl PartState {
+    fn cell(self) -> &'static str {
+        match self {
+            PartState::Solved => "⭐",
+            PartState::Unverified => "?",
+            PartState::Wrong => "✗",
+            PartState::Unsolved => "-",
+        }
+    }
+}
+
+/// A row of the report of a year
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub day: u8,
+    pub parts: [PartState; 2],
+    /// Median time of the parse and the parts which have an answer
+    pub median: Option<Duration>,
+    /// Modules of `aoc_common` used by the day, sorted
+    pub modules: Vec<String>,
+}
+
+/// Names of the `aoc_common` modules imported by a source file, in both the
+/// `aoc_common::grid::Grid` and the `aoc_common::{grid::Grid, topo::Topo}` forms
+pub fn common_modules(source: &str) -> Vec<String> {
+    let ident = |s: &str| -> String {
+        s.trim_start()
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .collect()
+    };
+    let mut modules = Vec::new();
+    for (i, _) in source.match_indices("aoc_common::") {
+        let rest = &source[i + "aoc_common::".len()..];
+        let Some(group) = rest.strip_prefix('{') else {
+            modules.push(ident(rest));
+            continue;
+        };
+        // The modules start the items of the group, which may have nested groups
+        modules.push(ident(group));
+        let mut depth = 0;
+        for (j, c) in group.char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' if depth == 0 => break,
+                '}' => depth -= 1,
+                ',' if depth == 0 => modules.push(ident(&group[j + 1..])),
+                _ => {}
+            }
+        }
+    }
+    modules.retain(|m| !m.is_empty());
+    modules.sort();
+    modules.dedup();
+    modules
+}
+
+/// Runs a day to find out which parts it solves, then times it on `runs` runs
+fn report_day(year: &Year, day: &'static Day, answers: &Answers, runs: usize) -> DayReport {
+    let records = run::run_day(year.year, day, &Source::Default, &Options::default());
+    let parts = [1, 2].map(|p| {
+        let record = records.iter().find(|r| r.part == p);
+        match record.filter(|r| r.status == Status::Ok) {
+            None => PartState::Unsolved,
+            Some(r) => match answers.get(day.day, p) {
+                None => PartState::Unverified,
+                Some(expected) if r.answer.as_deref() == Some(expected) => PartState::Solved,
+                Some(_) => PartState::Wrong,
+            },
+        }
+    });
+    // Only time the parts which completed, the others would panic or never end
+    let answered: Vec<u8> = (1..=2)
+        .filter(|&p| parts[p as usize - 1] != PartState::Unsolved)
+        .collect();
+    let median = match answered[..] {
+        [] => None,
+        [p] => Some(bench::bench_day(day, &Source::Default, Some(p), &[], runs, 1).cost()),
+        _ => Some(bench::bench_day(day, &Source::Default, None, &[], runs, 1).cost()),
+    };
+    let source = Path::new(year.root)
+        .join("src")
+        .join(format!("day{:02}.rs", day.day));
+    DayReport {
+        day: day.day,
+        parts,
+        median,
+        modules: fs::read_to_string(source)
+            .map(|s| common_modules(&s))
+            .unwrap_or_default(),
+    }
+}
+
+/// Renders the report of a year as a Markdown table. `sources` is the path of
+/// the year's source directory relative to the README.
+pub fn render(year: u16, sources: &str, reports: &[DayReport]) -> String {
+    let mut table = String::from("| Day | Part 1 | Part 2 | Median time | Common modules |\n");
+    table += "|-----|:------:|:------:|------------:|----------------|\n";
+    for r in reports {
+        let modules: Vec<String> = r.modules.iter().map(|m| format!("`{m}`")).collect();
+        table += &format!(
+            "| [{:02}](https://adventofcode.com/{year}/day/{}) ([code]({sources}/day{:02}.rs)) | {} | {} | {} | {} |\n",
+            r.day,
+            r.day,
+            r.day,
+            r.parts[0].cell(),
+            r.parts[1].cell(),
+            r.median.map_or("-".to_string(), format_duration),
+            modules.join(", ")
+        );
+    }
+    let stars = reports
+        .iter()
+        .flat_map(|r| r.parts)
+        .filter(|&p| p == PartState::Solved)
+        .count();
+    table += &format!(
+        "\n{stars} stars. ⭐ matches the recorded answer, ? has no recorded answer, ✗ differs from it.\n"
+    );
+    table
+}
+
+/// Replaces the content of the section of a year in a README, between the
+/// `<!-- report YEAR -->` and `<!-- /report YEAR -->` markers, adding the
+/// section at the end when there is none
+pub fn replace_section(readme: &str, year: u16, content: &str) -> String {
+    let start = format!("<!-- report {year} -->");
+    let end = format!("<!-- /report {year} -->");
+    let section = format!("{start}\n{content}{end}");
+    if let Some(i) = readme.find(&start)
+        && let Some(j) = readme[i..].find(&end)
+    {
+        return format!("{}{section}{}", &readme[..i], &readme[i + j + end.len()..]);
+    }
+    let mut readme = readme.to_string();
+    if !readme.is_empty() {
+        readme += if readme.ends_with('\n') { "\n" } else { "\n\n" };
+    }
+    readme + &format!("## {year}\n\n{section}\n")
+}
+
+/// Runs and times every day of a year, then rewrites its section of the README
+pub fn run(year: &Year, readme: &Path, runs: usize) -> Result<(), String> {
+    let answers = Answers::load(&Path::new(year.root).join("answers.toml"))?;
+    let mut reports = Vec::new();
+    for day in year.days {
+        let report = report_day(year, day, &answers, runs);
+        println!(
+            "Day {:02}: {} {} in {}",
+            report.day,
+            report.parts[0].cell(),
+            report.parts[1].cell(),
+            report.median.map_or("-".to_string(), format_duration)
+        );
+        reports.push(report);
+    }
+
+    let sources = Path::new(year.root).join("src");
+    let base = readme.parent().unwrap_or(Path::new(""));
+    let base = base.canonicalize().unwrap_or_else(|_| base.to_path_buf());
+    let sources: PathBuf = sources
+        .strip_prefix(&base)
+        .map_or_else(|_| sources.clone(), Path::to_path_buf);
+    let table = render(year.year, &sources.to_string_lossy(), &reports);
+
+    let content = match fs::read_to_string(readme) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("{}: {e}", readme.display())),
+    };
+    fs::write(readme, replace_section(&content, year.year, &table))
+        .map_err(|e| format!("{}: {e}", readme.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{DayReport, PartState, common_modules, render, replace_section};
+
+    #[test]
+    fn test_common_modules() {
+        assert_eq!(common_modules("use aoc_common::grid::Grid;"), ["grid"]);
+        let source = "use aoc_common::{\n    topo::Topo,\n    grid::{Grid, Pos},\n};\n\
+            fn f() { aoc_common::union_find::UnionFind::new(3); }\n\
+            use aoc_common::grid::Dir4;";
+        assert_eq!(common_modules(source), ["grid", "topo", "union_find"]);
+        assert!(common_modules("use std::fs;").is_empty());
+    }
+
+    #[test]
+    fn test_render() {
+        let reports = [
+            DayReport {
+                day: 7,
+                parts: [PartState::Solved, PartState::Wrong],
+                median: Some(Duration::from_micros(1_500)),
+                modules: vec!["grid".to_string(), "topo".to_string()],
+            },
+            DayReport {
+                day: 10,
+                parts: [PartState::Unverified, PartState::Unsolved],
+                median: None,
+                modules: Vec::new(),
+            },
+        ];
+        let table = render(2025, "2025/src", &reports);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[2],
+            "| [07](https://adventofcode.com/2025/day/7) ([code](2025/src/day07.rs)) | ⭐ | ✗ | 1.50ms | `grid`, `topo` |"
+        );
+        assert_eq!(
+            lines[3],
+            "| [10](https://adventofcode.com/2025/day/10) ([code](2025/src/day10.rs)) | ? | - | - |  |"
+        );
+        assert!(lines[5].starts_with("1 stars."));
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = replace_section("# Advent of Code\n", 2025, "old\n");
+        assert_eq!(
+            readme,
+            "# Advent of Code\n\n## 2025\n\n<!-- report 2025 -->\nold\n<!-- /report 2025 -->\n"
+        );
+        let readme = replace_section(&readme, 2024, "other\n");
+        let readme = replace_section(&readme, 2025, "new\n");
+        assert!(readme.contains("<!-- report 2025 -->\nnew\n<!-- /report 2025 -->\n\n## 2024"));
+        assert!(readme.contains("<!-- report 2024 -->\nother\n<!-- /report 2024 -->\n"));
+        assert!(!readme.contains("old"));
+        assert_eq!(
+            replace_section("", 2024, "x\n").lines().next(),
+            Some("## 2024")
+        );
+    }
+}
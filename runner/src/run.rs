@@ -0,0 +1,488 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        Arc, Once,
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde::{Serialize, Serializer};
+
+use crate::{
+    Day, Params,
+    bench::format_duration,
+    input::{self, Source},
+};
+
+/// How running a part went
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    Panicked,
+    /// Panicked in `unimplemented!()` or `todo!()`
+    Unimplemented,
+    /// Exceeded the time budget
+    TimedOut,
+}
+
+/// The result of running a part of a day
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Type of the answer, e.g. `usize`, or the variant of an `Answer`, e.g. `bitmap`.
+    /// Like the answer, it is missing when the part did not complete.
+    #[serde(rename = "type")]
+    pub answer_type: Option<&'static str>,
+    /// Wall time of the part, without the parsing, or the budget when it timed out
+    #[serde(rename = "time_ns", serialize_with = "as_nanos")]
+    pub time: Duration,
+    pub status: Status,
+    /// Panic or timeout message, when the part did not complete
+    pub message: Option<String>,
+}
+
+fn as_nanos<S: Serializer>(time: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(time.as_nanos())
+}
+
+/// Extracts the message of a panic, which is either a `&str` or a `String`
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+thread_local! {
+    /// Whether the current thread is running code under `catch`
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// Location of the last panic caught on the current thread
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Installs a panic hook which silences the panics caught by `catch` and records
+/// their location, while the other panics still go to the previous hook
+fn install_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                LOCATION.set(info.location().map(|l| l.to_string()));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Runs `f`, turning a panic into its message followed by its location
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    install_hook();
+    let was_catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(was_catching);
+    result.map_err(|payload| {
+        let message = panic_message(&*payload);
+        match LOCATION.take() {
+            Some(location) => format!("{message} ({location})"),
+            None => message,
+        }
+    })
+}
+
+/// Tells `unimplemented!()` and `todo!()` apart from the other panics by their message
+pub fn status_of(message: &str) -> Status {
+    if message.starts_with("not implemented") || message.starts_with("not yet implemented") {
+        Status::Unimplemented
+    } else {
+        Status::Panicked
+    }
+}
+
+/// How the parts of a day are run
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Only run this part
+    pub part: Option<u8>,
+    /// Give up on a phase after this long
+    pub timeout: Option<Duration>,
+    /// Values of parameters, overriding the defaults and the sidecar of the input
+    pub params: Vec<(String, String)>,
+}
+
+/// Stack size of the worker threads, as large as a main thread's since some
+/// solutions recurse deeply
+const STACK_SIZE: usize = 8 << 20;
+
+/// What a worker thread reports as it goes
+enum Event {
+    Parsed(Result<(), String>),
+    Solved(u8, Result<(String, &'static str), String>, Duration),
+}
+
+/// Parses the input of a day on a new thread, then solves the parts in order
+fn spawn_worker(
+    day: &'static Day,
+    input: Arc<str>,
+    params: Params,
+    parts: Vec<u8>,
+) -> Receiver<Event> {
+    let (sender, receiver) = mpsc::channel();
+    thread::Builder::new()
+        .name(format!("day{:02}", day.day))
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let input = match catch(|| day.parse(&input, &params)) {
+                Ok(input) => input,
+                Err(message) => {
+                    let _ = sender.send(Event::Parsed(Err(message)));
+                    return;
+                }
+            };
+            if sender.send(Event::Parsed(Ok(()))).is_err() {
+                return;
+            }
+            for part in parts {
+                let start = Instant::now();
+                let answer = catch(|| day.solve_typed(&*input, part));
+                if sender
+                    .send(Event::Solved(part, answer, start.elapsed()))
+                    .is_err()
+                {
+                    return;
+                }
+            }
+        })
+        .expect("failed to spawn a worker thread");
+    receiver
+}
+
+fn receive(
+    receiver: &Receiver<Event>,
+    timeout: Option<Duration>,
+) -> Result<Event, RecvTimeoutError> {
+    match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    }
+}
+
+/// Runs the parts of a day, or only the given one, on a worker thread. A panic
+/// while loading or parsing the input fails all the parts, while a panic in a
+/// part only fails it. Each phase gets at most the timeout: a part running longer
+/// is abandoned, still running, and the next part gets a fresh worker. The input
+/// is loaded once beforehand, as reading it again from stdin would find it empty.
+pub fn run_day(year: u16, day: &'static Day, source: &Source, options: &Options) -> Vec<Record> {
+    let timeout = options.timeout;
+    let record = |part, answer: Option<(String, &'static str)>, time, status, message| Record {
+        year,
+        day: day.day,
+        part,
+        answer_type: answer.as_ref().map(|&(_, t)| t),
+        answer: answer.map(|(a, _)| a),
+        time,
+        status,
+        message,
+    };
+    let failure = |error| match error {
+        RecvTimeoutError::Timeout => (
+            Status::TimedOut,
+            format!(
+                "exceeded the budget of {}",
+                format_duration(timeout.unwrap())
+            ),
+        ),
+        RecvTimeoutError::Disconnected => (
+            Status::Panicked,
+            "the worker thread stopped unexpectedly".to_string(),
+        ),
+    };
+
+    let mut parts: Vec<u8> = (1..=2)
+        .filter(|&p| options.part.is_none_or(|part| part == p))
+        .collect();
+    let load = || {
+        let params = Params::resolve(day, source, &options.params)?;
+        catch(|| input::load(source, day.path, day.embedded)).map(|input| (input.into(), params))
+    };
+    let (input, params): (Arc<str>, _) = match load() {
+        Ok(loaded) => loaded,
+        Err(message) => {
+            let status = status_of(&message);
+            return parts
+                .into_iter()
+                .map(|p| record(p, None, Duration::ZERO, status, Some(message.clone())))
+                .collect();
+        }
+    };
+    let mut records = Vec::new();
+    while !parts.is_empty() {
+        let receiver = spawn_worker(day, input.clone(), params.clone(), parts.clone());
+        let (status, message) = match receive(&receiver, timeout) {
+            Ok(Event::Parsed(Ok(()))) => (Status::Ok, String::new()),
+            Ok(Event::Parsed(Err(message))) => (status_of(&message), message),
+            Ok(Event::Solved(..)) => unreachable!("solved a part before parsing"),
+            Err(error) => failure(error),
+        };
+        if status != Status::Ok {
+            let time = if status == Status::TimedOut {
+                timeout.unwrap()
+            } else {
+                Duration::ZERO
+            };
+            records.extend(
+                parts
+                    .drain(..)
+                    .map(|p| record(p, None, time, status, Some(message.clone()))),
+            );
+            break;
+        }
+
+        while !parts.is_empty() {
+            let part = parts.remove(0);
+            match receive(&receiver, timeout) {
+                Ok(Event::Solved(p, Ok(answer), time)) => {
+                    records.push(record(p, Some(answer), time, Status::Ok, None));
+                }
+                Ok(Event::Solved(p, Err(message), time)) => {
+                    records.push(record(p, None, time, status_of(&message), Some(message)));
+                }
+                Ok(Event::Parsed(_)) => unreachable!("parsed twice"),
+                Err(error) => {
+                    let (status, message) = failure(error);
+                    records.push(record(
+                        part,
+                        None,
+                        timeout.unwrap_or_default(),
+                        status,
+                        Some(message),
+                    ));
+                    // The worker may still be busy with this part, the next ones get a new one
+                    break;
+                }
+            }
+        }
+    }
+    records
+}
+
+/// Runs the days on a pool of `jobs` threads, each day still timed on its own.
+/// `on_day` gets the records of each day in the order of `days`, as soon as the
+/// day and the ones before it are done.
+pub fn run_days(
+    year: u16,
+    days: &[&'static Day],
+    source: &Source,
+    options: &Options,
+    jobs: usize,
+    mut on_day: impl FnMut(Vec<Record>),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&day) = days.get(i) else { break };
+                    let records = run_day(year, day, source, options);
+                    if sender.send((i, records)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // Hold back the days completed before an earlier one
+        let mut pending = BTreeMap::new();
+        let mut printed = 0;
+        for (i, records) in receiver {
+            pending.insert(i, records);
+            while let Some(records) = pending.remove(&printed) {
+                on_day(records);
+                printed += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, thread, time::Duration};
+
+    use aoc_common::answer::Answer;
+
+    use super::{Options, Status, catch, run_day, run_days};
+    use crate::{Day, Solution, input::Source, test_util::TempDir};
+
+    struct Slow;
+
+    impl Solution for Slow {
+        type Input = u64;
+        type Answer = u64;
+
+        fn parse(input: &str) -> u64 {
+            input.trim().parse().unwrap()
+        }
+
+        fn part1(input: &u64) -> u64 {
+            thread::sleep(Duration::from_secs(*input));
+            1
+        }
+
+        fn part2(input: &u64) -> u64 {
+            assert!(*input < 10, "too large");
+            2
+        }
+    }
+
+    struct Mixed;
+
+    impl Solution for Mixed {
+        type Input = ();
+        type Answer = Answer;
+
+        fn parse(_: &str) {}
+
+        fn part1(_: &()) -> Answer {
+            Answer::from(7u8)
+        }
+
+        fn part2(_: &()) -> Answer {
+            Answer::from("abc")
+        }
+    }
+
+    struct Drained;
+
+    impl Solution for Drained {
+        type Input = String;
+        type Answer = String;
+
+        fn parse(input: &str) -> String {
+            input.to_string()
+        }
+
+        fn part1(input: &String) -> String {
+            fs::remove_file(input).unwrap();
+            thread::sleep(Duration::from_secs(1));
+            String::new()
+        }
+
+        fn part2(input: &String) -> String {
+            input.clone()
+        }
+    }
+
+    static SLOW: Day = Day::new::<Slow>(1, "", Some("5"));
+    static FAST: Day = Day::new::<Slow>(3, "", Some("0"));
+    static PANICKING: Day = Day::new::<Slow>(2, "", Some("10"));
+    static MIXED: Day = Day::new::<Mixed>(4, "", Some(""));
+    static DRAINED: Day = Day::new::<Drained>(5, "", None);
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(catch(|| 42), Ok(42));
+        let error = catch(|| -> u8 { panic!("bad input {}", 7) }).unwrap_err();
+        assert!(
+            error.starts_with("bad input 7 (") && error.contains("src/run.rs:"),
+            "{error}"
+        );
+        let error = catch(|| -> u8 { unimplemented!() }).unwrap_err();
+        assert!(error.starts_with("not implemented ("), "{error}");
+    }
+
+    #[test]
+    fn test_run_day() {
+        let options = Options {
+            timeout: Some(Duration::from_millis(100)),
+            ..Options::default()
+        };
+        let records = run_day(2025, &SLOW, &Source::Default, &options);
+        let statuses: Vec<_> = records.iter().map(|r| (r.part, r.status)).collect();
+        assert_eq!(statuses, [(1, Status::TimedOut), (2, Status::Ok)]);
+        assert_eq!(records[1].answer.as_deref(), Some("2"));
+
+        let options = Options {
+            part: Some(2),
+            ..Options::default()
+        };
+        let records = run_day(2025, &PANICKING, &Source::Default, &options);
+        assert_eq!(records[0].status, Status::Panicked);
+        assert!(
+            records[0]
+                .message
+                .as_deref()
+                .unwrap()
+                .starts_with("too large")
+        );
+    }
+
+    #[test]
+    fn test_answer_type() {
+        let records = run_day(2025, &FAST, &Source::Default, &Options::default());
+        assert_eq!(records[0].answer_type, Some("u64"));
+        let records = run_day(2025, &MIXED, &Source::Default, &Options::default());
+        let types: Vec<_> = records.iter().map(|r| r.answer_type).collect();
+        assert_eq!(types, [Some("int"), Some("str")]);
+
+        // A part without an answer has no type either
+        let options = Options {
+            part: Some(2),
+            ..Options::default()
+        };
+        let records = run_day(2025, &PANICKING, &Source::Default, &options);
+        assert_eq!(records[0].answer_type, None);
+    }
+
+    #[test]
+    fn test_run_day_reads_once() {
+        // The input is the path of its own file, which part 1 removes before
+        // timing out, so part 2 only gets it if it is not read again
+        let dir = TempDir::new("run-once");
+        let path = dir.join("input.txt");
+        fs::write(&path, path.to_str().unwrap()).unwrap();
+        let options = Options {
+            timeout: Some(Duration::from_millis(100)),
+            ..Options::default()
+        };
+        let records = run_day(2025, &DRAINED, &Source::File(path.clone()), &options);
+        let statuses: Vec<_> = records.iter().map(|r| (r.part, r.status)).collect();
+        assert_eq!(statuses, [(1, Status::TimedOut), (2, Status::Ok)]);
+        assert_eq!(records[1].answer.as_deref(), path.to_str());
+    }
+
+    #[test]
+    fn test_run_days() {
+        let mut days = Vec::new();
+        run_days(
+            2025,
+            &[&SLOW, &FAST, &PANICKING],
+            &Source::Default,
+            &Options {
+                timeout: Some(Duration::from_millis(100)),
+                ..Options::default()
+            },
+            3,
+            |records| days.push(records[0].day),
+        );
+        assert_eq!(days, [1, 3, 2]);
+    }
+}
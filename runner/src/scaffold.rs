@@ -0,0 +1,126 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Inserts `line` among the `peers` lines, which are recognized by `day_of`
+/// returning their day, such that the days stay sorted.
+fn insert_sorted(
+    content: &str,
+    day: u8,
+    line: impl Fn(&str) -> String,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Option<String> {
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    let peers: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| day_of(l.trim()).map(|d| (i, d)))
+        .collect();
+    let &(first, _) = peers.first()?;
+    if peers.iter().any(|&(_, d)| d == day) {
+        return None;
+    }
+    let idx = peers
+        .iter()
+        .rfind(|&&(_, d)| d < day)
+        .map_or(first, |&(i, _)| i + 1);
+    let peer = &lines[first];
+    let indent = peer[..peer.len() - peer.trim_start().len()].to_string();
+    lines.insert(idx, format!("{indent}{}", line(&format!("{day:02}"))));
+    Some(lines.join("\n") + "\n")
+}
+
+/// Registers a new day in the content of a year's `lib.rs`, both as a module
+/// and in the `days` of its `Year`
+pub fn register(lib: &str, day: u8) -> Result<String, String> {
+    let lib = insert_sorted(
+        lib,
+        day,
+        |nn| format!("pub mod day{nn};"),
+        |l| {
+            l.strip_prefix("pub mod day")?
+                .strip_suffix(';')?
+                .parse()
+                .ok()
+        },
+    )
+    .ok_or("could not add the module of the day")?;
+    insert_sorted(
+        &lib,
+        day,
+        |nn| format!("day!({day}, day{nn}::Day{nn}),"),
+        |l| l.strip_prefix("day!(")?.split_once(',')?.0.parse().ok(),
+    )
+    .ok_or_else(|| "could not register the day in the `Year`".to_string())
+}
+
+/// Creates the source file of a new day from the year's `template.rs`, registers
+/// it and creates an empty asset if there is none yet. Returns the created files.
+pub fn new_day(year_dir: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day {day}, expected 1 to 25"));
+    }
+    let nn = format!("{day:02}");
+    let source = year_dir.join("src").join(format!("day{nn}.rs"));
+    if source.exists() {
+        return Err(format!("{} already exists", source.display()));
+    }
+    let read = |p: &Path| fs::read_to_string(p).map_err(|e| format!("{}: {e}", p.display()));
+    let write = |p: &Path, c: &str| fs::write(p, c).map_err(|e| format!("{}: {e}", p.display()));
+
+    let template = read(&year_dir.join("template.rs"))?;
+    let lib_path = year_dir.join("src").join("lib.rs");
+    let lib = register(&read(&lib_path)?, day)?;
+
+    let mut created = Vec::new();
+    write(&source, &template.replace("XY", &nn))?;
+    created.push(source);
+    write(&lib_path, &lib)?;
+    let asset = year_dir.join("assets").join(format!("day{nn}.txt"));
+    if !asset.exists() {
+        write(&asset, "")?;
+        created.push(asset);
+    }
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::register;
+
+    const LIB: &str = "\
+use aoc_runner::{Year, day};
+
+pub mod day01;
+pub mod day03;
+pub mod grid;
+
+pub const YEAR: Year = Year {
+    year: 2025,
+    root: env!(\"CARGO_MANIFEST_DIR\"),
+    days: &[
+        day!(1, day01::Day01),
+        day!(3, day03::Day03),
+    ],
+};
+";
+
+    #[test]
+    fn test_register() {
+        let lib = register(LIB, 2).unwrap();
+        assert!(lib.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(lib.contains(
+            "        day!(1, day01::Day01),\n        day!(2, day02::Day02),\n        day!(3, day03::Day03),\n"
+        ));
+
+        let lib = register(&lib, 12).unwrap();
+        assert!(lib.contains("pub mod day03;\npub mod day12;\npub mod grid;\n"));
+        assert!(
+            lib.contains("        day!(3, day03::Day03),\n        day!(12, day12::Day12),\n    ],")
+        );
+
+        assert!(register(LIB, 3).is_err());
+        assert!(register("pub mod grid;\n", 3).is_err());
+    }
+}
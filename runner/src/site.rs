@@ -0,0 +1,165 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use clap::Args;
+use ureq::Agent;
+
+const USER_AGENT: &str = concat!(
+    "github.com/brunnerant/advent-of-code aoc-runner/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Environment variable holding the session cookie, used when no session file is given
+const SESSION_VAR: &str = "AOC_SESSION";
+
+#[derive(Args, Debug)]
+pub struct SiteArgs {
+    /// Base URL of the Advent of Code website
+    #[arg(long, env = "AOC_BASE_URL", default_value = "https://adventofcode.com")]
+    pub base_url: String,
+
+    /// File holding the session cookie [default: AOC_SESSION or `.session` at the repository root]
+    #[arg(long, value_name = "PATH")]
+    pub session_file: Option<PathBuf>,
+}
+
+impl SiteArgs {
+    /// Reads the session cookie from the session file, the `AOC_SESSION` variable
+    /// or the `.session` file at the root of the repository, in this order
+    pub fn session(&self, repo_root: &Path) -> Result<String, String> {
+        let path = match (&self.session_file, env::var(SESSION_VAR)) {
+            (Some(path), _) => path.clone(),
+            (None, Ok(session)) => return Ok(session.trim().to_string()),
+            (None, Err(_)) => repo_root.join(".session"),
+        };
+        fs::read_to_string(&path)
+            .map(|s| s.trim().to_string())
+            .map_err(|e| format!("could not read the session from {}: {e}", path.display()))
+    }
+
+    pub fn client(&self, repo_root: &Path) -> Result<Client, String> {
+        Ok(Client::new(&self.base_url, self.session(repo_root)?))
+    }
+}
+
+/// An authenticated client of the Advent of Code website
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String) -> Self {
+        let config = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .http_status_as_error(false)
+            .build();
+        Self {
+            agent: config.into(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    fn read(url: &str, response: ureq::http::Response<ureq::Body>) -> Result<String, String> {
+        let status = response.status();
+        let body = response
+            .into_body()
+            .read_to_string()
+            .map_err(|e| format!("{url}: {e}"))?;
+        if status.is_success() {
+            Ok(body)
+        } else {
+            let reason = body.lines().next().unwrap_or_default();
+            Err(format!("{url}: HTTP {status}: {reason}"))
+        }
+    }
+
+    /// Gets a page of the website, e.g. `/2025/day/7/input`
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        let url = self.url(path);
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .call()
+            .map_err(|e| format!("{url}: {e}"))?;
+        Self::read(&url, response)
+    }
+
+    /// Posts a form to the website, e.g. an answer to `/2025/day/7/answer`
+    pub fn post_form(&self, path: &str, fields: &[(&str, &str)]) -> Result<String, String> {
+        let url = self.url(path);
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .send_form(fields.iter().copied())
+            .map_err(|e| format!("{url}: {e}"))?;
+        Self::read(&url, response)
+    }
+}
+
+/// A local HTTP server answering requests with canned responses, for testing the client
+#[cfg(test)]
+pub mod mock {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    /// A request received by the mock server
+    pub struct Request {
+        pub head: String,
+        pub body: String,
+    }
+
+    /// Serves the responses in order, one per connection, and returns the base URL
+    /// of the server and a channel receiving the requests.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut head = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        length = value.trim().parse().unwrap();
+                    }
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    head.push_str(&line);
+                }
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+                let body_len = body.len();
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {body_len}\r\nConnection: close\r\n\r\n{body}"
+                )
+                .unwrap();
+                let body = String::from_utf8(request_body).unwrap();
+                sender.send(Request { head, body }).unwrap();
+            }
+        });
+        (url, receiver)
+    }
+}
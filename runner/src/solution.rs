@@ -0,0 +1,166 @@
+use std::{
+    any::{Any, type_name},
+    fmt::Display,
+};
+
+use aoc_common::answer::Answer;
+
+use crate::params::{Param, Params};
+
+/// The solution of a day: how to parse its input and solve both parts
+pub trait Solution {
+    type Input: 'static;
+    type Answer: Display + 'static;
+
+    /// Named parameters of the puzzle, with their defaults for the real inputs
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(input: &str) -> Self::Input;
+
+    /// Parses the input with the values of the parameters, which only days
+    /// declaring parameters need to implement. `parse` should then call it
+    /// with `Params::defaults(Self::PARAMS)`.
+    fn parse_with(input: &str, _params: &Params) -> Self::Input {
+        Self::parse(input)
+    }
+    fn part1(input: &Self::Input) -> Self::Answer;
+    fn part2(input: &Self::Input) -> Self::Answer;
+}
+
+/// A type-erased `Solution` of a given day, as registered by each year.
+/// Use the `day!` macro to build it.
+pub struct Day {
+    pub day: u8,
+    /// Default location of the input
+    pub path: &'static str,
+    /// Compile-time copy of the input, with the `embed` feature
+    pub embedded: Option<&'static str>,
+    parse: fn(&str, &Params) -> Box<dyn Any>,
+    params: &'static [Param],
+    part1: fn(&dyn Any) -> (String, &'static str),
+    part2: fn(&dyn Any) -> (String, &'static str),
+}
+
+fn parse<S: Solution>(input: &str, params: &Params) -> Box<dyn Any> {
+    Box::new(S::parse_with(input, params))
+}
+
+/// The name of a type without the module path of its outer type, e.g. `String` rather than
+/// `alloc::string::String`, as the full path `type_name` gives is not stable
+fn short_type_name<T>() -> &'static str {
+    let name = type_name::<T>();
+    let end = name.find('<').unwrap_or(name.len());
+    match name[..end].rfind("::") {
+        Some(i) => &name[i + 2..],
+        None => name,
+    }
+}
+
+/// Displays an answer along with its type, which is the variant for an `Answer`
+fn typed<A: Display + 'static>(answer: A) -> (String, &'static str) {
+    let answer_type = match (&answer as &dyn Any).downcast_ref::<Answer>() {
+        Some(answer) => answer.kind(),
+        None => short_type_name::<A>(),
+    };
+    (answer.to_string(), answer_type)
+}
+
+fn part1<S: Solution>(input: &dyn Any) -> (String, &'static str) {
+    typed(S::part1(input.downcast_ref().unwrap()))
+}
+
+fn part2<S: Solution>(input: &dyn Any) -> (String, &'static str) {
+    typed(S::part2(input.downcast_ref().unwrap()))
+}
+
+impl Day {
+    pub const fn new<S: Solution>(
+        day: u8,
+        path: &'static str,
+        embedded: Option<&'static str>,
+    ) -> Self {
+        Self {
+            day,
+            path,
+            embedded,
+            parse: parse::<S>,
+            params: S::PARAMS,
+            part1: part1::<S>,
+            part2: part2::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str, params: &Params) -> Box<dyn Any> {
+        (self.parse)(input, params)
+    }
+
+    pub fn params(&self) -> &'static [Param] {
+        self.params
+    }
+
+    /// Solves the given part (1 or 2) on an input returned by `parse`
+    pub fn solve(&self, input: &dyn Any, part: u8) -> String {
+        self.solve_typed(input, part).0
+    }
+
+    /// Solves the given part like `solve`, also returning the type of the answer:
+    /// its name, e.g. `usize`, or the variant for an `Answer`, e.g. `bitmap`
+    pub fn solve_typed(&self, input: &dyn Any, part: u8) -> (String, &'static str) {
+        if part == 1 {
+            (self.part1)(input)
+        } else {
+            (self.part2)(input)
+        }
+    }
+}
+
+/// Registers a day from its module and solution type, e.g. `day!(7, day07::Day07)`.
+/// The input defaults to the calling crate's `assets/day07.txt`, which is embedded at
+/// compile time when the calling crate's `embed` feature is on.
+#[macro_export]
+macro_rules! day {
+    ($day:literal, $module:ident :: $solution:ident) => {{
+        #[cfg(feature = "embed")]
+        const EMBEDDED: Option<&str> = Some(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/assets/",
+            stringify!($module),
+            ".txt"
+        )));
+        #[cfg(not(feature = "embed"))]
+        const EMBEDDED: Option<&str> = None;
+        $crate::Day::new::<$module::$solution>(
+            $day,
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/",
+                stringify!($module),
+                ".txt"
+            ),
+            EMBEDDED,
+        )
+    }};
+}
+
+/// All the solved days of a year
+pub struct Year {
+    pub year: u16,
+    /// Directory of the year's crate, holding its `assets` and `answers.toml`
+    pub root: &'static str,
+    pub days: &'static [Day],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::short_type_name;
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(short_type_name::<usize>(), "usize");
+        assert_eq!(short_type_name::<String>(), "String");
+        assert_eq!(
+            short_type_name::<Vec<String>>(),
+            "Vec<alloc::string::String>"
+        );
+    }
+}
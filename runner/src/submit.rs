@@ -0,0 +1,280 @@
+use std::{
+    fmt, fs, io,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::site::Client;
+
+/// What the website answered to a submission
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about the direction
+    Wrong,
+    /// Submitted too early after a previous answer, nothing was checked
+    RateLimited,
+    /// The part is locked or already solved, nothing was checked
+    WrongLevel,
+}
+
+impl Verdict {
+    fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "that's the right answer",
+            Verdict::TooHigh => "that's not the right answer, it's too high",
+            Verdict::TooLow => "that's not the right answer, it's too low",
+            Verdict::Wrong => "that's not the right answer",
+            Verdict::RateLimited => "an answer was given too recently",
+            Verdict::WrongLevel => "this part is not the one being solved",
+        })
+    }
+}
+
+/// Parses a duration such as `1m 2s` or `45s`
+fn parse_wait(wait: &str) -> Option<Duration> {
+    let mut secs = 0;
+    for token in wait.split_whitespace() {
+        let unit = token.chars().last()?;
+        let value: u64 = token.strip_suffix(unit)?.parse().ok()?;
+        secs += value
+            * match unit {
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return None,
+            };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Reads the verdict in the page returned after submitting an answer, along with
+/// the time left to wait when rate-limited
+pub fn parse_response(page: &str) -> Result<(Verdict, Option<Duration>), String> {
+    let verdict = if page.contains("That's the right answer") {
+        Verdict::Correct
+    } else if page.contains("That's not the right answer") {
+        if page.contains("too high") {
+            Verdict::TooHigh
+        } else if page.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if page.contains("You gave an answer too recently") {
+        let wait = page
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_wait(wait));
+        return Ok((Verdict::RateLimited, wait));
+    } else if page.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        return Err("could not understand the response of the website".to_string());
+    };
+    Ok((verdict, None))
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch
+    pub time: u64,
+}
+
+/// All the answers submitted for a year, stored in its `guesses.toml`
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct History {
+    #[serde(default, rename = "guess")]
+    pub guesses: Vec<Guess>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = toml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(path, content).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    fn guesses(&self, day: u8, part: u8) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |g| g.day == day && g.part == part)
+    }
+
+    /// Checks that an answer is not already ruled out by the previous guesses
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Result<(), String> {
+        let value = answer.parse::<i128>().ok();
+        for guess in self.guesses(day, part) {
+            if guess.verdict == Verdict::Correct {
+                return Err(format!(
+                    "this part was already solved with {}",
+                    guess.answer
+                ));
+            }
+            if guess.verdict.is_wrong() && guess.answer == answer {
+                return Err(format!("{answer} was already submitted: {}", guess.verdict));
+            }
+            let Some((value, bound)) = value.zip(guess.answer.parse::<i128>().ok()) else {
+                continue;
+            };
+            if guess.verdict == Verdict::TooHigh && value >= bound {
+                return Err(format!("{answer} is not below {bound}, which is too high"));
+            }
+            if guess.verdict == Verdict::TooLow && value <= bound {
+                return Err(format!("{answer} is not above {bound}, which is too low"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submits an answer unless the guess history rules it out, and records the guess
+/// in the history file. Returns the verdict and the time left to wait when rate-limited.
+pub fn submit(
+    client: &Client,
+    history_path: &Path,
+    (year, day, part): (u16, u8, u8),
+    answer: &str,
+) -> Result<(Verdict, Option<Duration>), String> {
+    let mut history = History::load(history_path)?;
+    history.check(day, part, answer)?;
+
+    let level = part.to_string();
+    let page = client.post_form(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    let (verdict, wait) = parse_response(&page)?;
+
+    history.guesses.push(Guess {
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+        time: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+    });
+    history.save(history_path)?;
+    Ok((verdict, wait))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{History, Verdict, parse_response, submit};
+    use crate::{
+        site::{Client, mock},
+        test_util::TempDir,
+    };
+
+    #[test]
+    fn test_parse_response() {
+        let page = |p: &str| format!("<main>\n<article><p>{p}</p></article>\n</main>");
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Ok((Verdict::Correct, None))
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Ok((Verdict::TooHigh, None))
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Ok((Verdict::TooLow, None))
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer. If you're stuck, ...")),
+            Ok((Verdict::Wrong, None))
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 12s left to wait."
+            )),
+            Ok((Verdict::RateLimited, Some(Duration::from_secs(72))))
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Ok((Verdict::WrongLevel, None))
+        );
+        assert!(parse_response("<html></html>").is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, requests) = mock::serve(vec![
+            (
+                200,
+                "<article><p>That's not the right answer; your answer is too high.</p>",
+            ),
+            (
+                200,
+                "<article><p>That's not the right answer; your answer is too low.</p>",
+            ),
+            (200, "<article><p>That's the right answer!</p>"),
+        ]);
+        let client = Client::new(&url, "abc".to_string());
+        let dir = TempDir::new("submit");
+        let history = dir.join("guesses.toml");
+
+        let result = submit(&client, &history, (2025, 3, 2), "100");
+        assert_eq!(result, Ok((Verdict::TooHigh, None)));
+        let request = requests.recv().unwrap();
+        assert!(request.head.starts_with("POST /2025/day/3/answer HTTP/1.1"));
+        assert_eq!(request.body, "level=2&answer=100");
+
+        // Ruled out by the history, without any request
+        assert!(submit(&client, &history, (2025, 3, 2), "100").is_err());
+        assert!(submit(&client, &history, (2025, 3, 2), "150").is_err());
+
+        assert_eq!(
+            submit(&client, &history, (2025, 3, 2), "40"),
+            Ok((Verdict::TooLow, None))
+        );
+        assert!(submit(&client, &history, (2025, 3, 2), "12").is_err());
+        assert_eq!(
+            submit(&client, &history, (2025, 3, 2), "64"),
+            Ok((Verdict::Correct, None))
+        );
+        assert!(submit(&client, &history, (2025, 3, 2), "65").is_err());
+
+        let history = History::load(&history).unwrap();
+        let verdicts: Vec<_> = history.guesses.iter().map(|g| g.verdict).collect();
+        assert_eq!(
+            verdicts,
+            [Verdict::TooHigh, Verdict::TooLow, Verdict::Correct]
+        );
+    }
+}
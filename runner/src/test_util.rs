@@ -0,0 +1,33 @@
+use std::{
+    env, fs,
+    ops::Deref,
+    path::{Path, PathBuf},
+    process,
+};
+
+/// A directory of the system temp dir for a test, removed with its content when
+/// dropped, so also when an assertion fails
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    /// Creates `aoc-runner-{name}-{pid}`, the name telling apart the tests which run in parallel
+    pub fn new(name: &str) -> Self {
+        let path = env::temp_dir().join(format!("aoc-runner-{name}-{}", process::id()));
+        fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
@@ -0,0 +1,72 @@
+use std::{path::Path, time::Duration};
+
+use crate::{
+    Day, Year,
+    answers::Answers,
+    input::Source,
+    run::{self, Options, Status},
+};
+
+/// Checks the answers of the given days on their real input against the
+/// year's `answers.toml`, giving up on a part after `timeout`. Returns whether
+/// all the recorded answers match.
+pub fn run(year: &Year, days: &[&'static Day], timeout: Option<Duration>) -> bool {
+    let path = Path::new(year.root).join("answers.toml");
+    let answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Could not load the answers: {e}");
+            return false;
+        }
+    };
+
+    let mut ok = true;
+    let mut missing = Vec::new();
+    for day in days {
+        let expected: Vec<_> = (1..=2).map(|p| (p, answers.get(day.day, p))).collect();
+        if expected.iter().all(|(_, e)| e.is_none()) {
+            missing.push(format!("day {:02}", day.day));
+            continue;
+        }
+        // Only compute the parts with a recorded answer
+        let part = match expected[..] {
+            [(_, Some(_)), (_, Some(_))] => None,
+            [(p, Some(_)), _] | [_, (p, Some(_))] => Some(p),
+            _ => unreachable!(),
+        };
+        let options = Options {
+            part,
+            timeout,
+            ..Options::default()
+        };
+        let records = run::run_day(year.year, day, &Source::Default, &options);
+        for (p, expected) in expected {
+            let Some(expected) = expected else {
+                missing.push(format!("day {:02} part {p}", day.day));
+                continue;
+            };
+            let record = records.iter().find(|r| r.part == p).unwrap();
+            match (record.status, &record.answer) {
+                (Status::Ok, Some(answer)) if answer == expected => {
+                    println!("Day {:02} - Part {p}: ok", day.day);
+                }
+                (Status::Ok, Some(answer)) => {
+                    ok = false;
+                    println!("Day {:02} - Part {p}: mismatch", day.day);
+                    println!("  - {expected}");
+                    println!("  + {answer}");
+                }
+                _ => {
+                    ok = false;
+                    let message = record.message.as_deref().unwrap_or_default();
+                    println!("Day {:02} - Part {p}: failed: {message}", day.day);
+                }
+            }
+        }
+    }
+
+    if !missing.is_empty() {
+        println!("No recorded answer for {}", missing.join(", "));
+    }
+    ok
+}
@@ -0,0 +1,225 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{Day, bench::format_duration, input::Source};
+
+/// How often the watched files are checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The outcome of a part in a run of the day, as reported by `--format json`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub time: Duration,
+    pub status: String,
+    pub message: Option<String>,
+}
+
+/// Reads the records printed by `--format json`
+fn parse_runs(json: &str) -> Result<Vec<PartRun>, String> {
+    let records: Vec<serde_json::Value> = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    records
+        .iter()
+        .map(|r| {
+            let string = |key: &str| r[key].as_str().map(String::from);
+            Ok(PartRun {
+                part: r["part"].as_u64().ok_or("missing part")? as u8,
+                answer: string("answer"),
+                time: Duration::from_nanos(r["time_ns"].as_u64().ok_or("missing time_ns")?),
+                status: string("status").ok_or("missing status")?,
+                message: string("message"),
+            })
+        })
+        .collect()
+}
+
+/// Describes a part compared to its previous run: the answer, or its diff
+/// when it changed, and the change in timing
+pub fn describe(previous: Option<&PartRun>, run: &PartRun) -> String {
+    let outcome = |r: &PartRun| match (&r.answer, &r.message) {
+        (Some(answer), _) if r.status == "ok" => answer.clone(),
+        (_, Some(message)) => format!("{}: {message}", r.status),
+        _ => r.status.clone(),
+    };
+    let current = outcome(run);
+    let mut line = format!("Part {}: ", run.part);
+    match previous.map(outcome) {
+        Some(previous) if previous != current => {
+            line += &format!("{current} (was {previous})");
+        }
+        Some(_) => line += &format!("{current} (unchanged)"),
+        None => line += &current,
+    }
+    line += &format!(" in {}", format_duration(run.time));
+    if let Some(previous) = previous.filter(|p| !p.time.is_zero()) {
+        let ratio = run.time.as_secs_f64() / previous.time.as_secs_f64();
+        line += &format!(
+            " ({:+.0}% from {})",
+            (ratio - 1.0) * 100.0,
+            format_duration(previous.time)
+        );
+    }
+    line
+}
+
+/// Last modification time of each file, `None` for the missing ones
+fn mtimes(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|p| fs::metadata(p).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Runs `cargo` on the year's crate, returning whether it succeeded and its
+/// standard output and error
+fn cargo(root: &Path, args: &[String]) -> Result<(bool, String, String), String> {
+    // Cargo tells the programs it runs where it is
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let output = Command::new(cargo)
+        .arg(&args[0])
+        .arg("--manifest-path")
+        .arg(root.join("Cargo.toml"))
+        .args(&args[1..])
+        .output()
+        .map_err(|e| format!("cargo: {e}"))?;
+    Ok((
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    ))
+}
+
+/// Runs the tests of the day, then the day itself, printing how its parts
+/// changed since `previous`. Returns the new runs, or an error when the day
+/// could not be built or run.
+fn run_once(
+    root: &Path,
+    day: &Day,
+    run_args: &[String],
+    previous: &[PartRun],
+) -> Result<Vec<PartRun>, String> {
+    let filter = format!("day{:02}::", day.day);
+    let (passed, stdout, stderr) = cargo(root, &["test".into(), "--quiet".into(), filter])?;
+    if passed {
+        println!("Tests: passed");
+    } else {
+        println!("Tests: failed");
+        print!("{stdout}");
+        eprint!("{stderr}");
+    }
+
+    let mut args: Vec<String> = ["run", "--quiet", "--", "--format", "json"]
+        .map(String::from)
+        .to_vec();
+    args.extend(["--day".to_string(), day.day.to_string()]);
+    args.extend_from_slice(run_args);
+    let (_, stdout, stderr) = cargo(root, &args)?;
+    // The day exits with an error when a part panics, but still prints its records
+    let runs = parse_runs(&stdout).map_err(|_| format!("Run failed\n{stderr}"))?;
+    for run in &runs {
+        let prev = previous.iter().find(|p| p.part == run.part);
+        println!("Day {:02} - {}", day.day, describe(prev, run));
+    }
+    Ok(runs)
+}
+
+/// Re-runs the tests of a day and the day itself each time its source file
+/// or its input change, until interrupted. `run_args` are passed on to the
+/// runs of the day, e.g. `--part 1`.
+pub fn watch(root: &Path, day: &Day, source: &Source, run_args: &[String]) -> ! {
+    let input = match source {
+        Source::File(path) => path.clone(),
+        Source::Default | Source::Stdin => PathBuf::from(day.path),
+    };
+    let paths = vec![
+        root.join("src").join(format!("day{:02}.rs", day.day)),
+        input,
+    ];
+    let names: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+    println!("Watching {}", names.join(" and "));
+
+    let mut seen = mtimes(&paths);
+    let mut previous = Vec::new();
+    loop {
+        match run_once(root, day, run_args, &previous) {
+            Ok(runs) => previous = runs,
+            Err(e) => println!("{e}"),
+        }
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = mtimes(&paths);
+            if current != seen {
+                let changed: Vec<&str> = (0..paths.len())
+                    .filter(|&i| current[i] != seen[i])
+                    .map(|i| names[i].as_str())
+                    .collect();
+                println!("\nChanged: {}", changed.join(", "));
+                seen = current;
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{PartRun, describe, parse_runs};
+
+    fn run(answer: &str, millis: u64) -> PartRun {
+        PartRun {
+            part: 1,
+            answer: Some(answer.to_string()),
+            time: Duration::from_millis(millis),
+            status: "ok".to_string(),
+            message: None,
+        }
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(describe(None, &run("42", 10)), "Part 1: 42 in 10.0ms");
+        assert_eq!(
+            describe(Some(&run("41", 20)), &run("42", 10)),
+            "Part 1: 42 (was 41) in 10.0ms (-50% from 20.0ms)"
+        );
+        assert_eq!(
+            describe(Some(&run("42", 10)), &run("42", 15)),
+            "Part 1: 42 (unchanged) in 15.0ms (+50% from 10.0ms)"
+        );
+        let panicked = PartRun {
+            answer: None,
+            status: "panicked".to_string(),
+            message: Some("oops".to_string()),
+            ..run("", 1)
+        };
+        assert_eq!(
+            describe(Some(&run("42", 1)), &panicked),
+            "Part 1: panicked: oops (was 42) in 1.00ms (+0% from 1.00ms)"
+        );
+    }
+
+    #[test]
+    fn test_parse_runs() {
+        let json = r#"[{"year": 2025, "day": 9, "part": 2, "answer": "7", "type": "usize",
+            "time_ns": 1500, "status": "ok", "message": null}]"#;
+        assert_eq!(
+            parse_runs(json),
+            Ok(vec![PartRun {
+                part: 2,
+                answer: Some("7".to_string()),
+                time: Duration::from_nanos(1500),
+                status: "ok".to_string(),
+                message: None,
+            }])
+        );
+        assert!(parse_runs("").is_err());
+    }
+}